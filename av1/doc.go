@@ -1,4 +1,5 @@
 /*
 Package av1 decodes (parses) and encodes (writes) AV1 CodecConfigurationRecord.
+It also parses OBU headers and the start of sequence header OBUs, and creates codecs strings.
 */
 package av1
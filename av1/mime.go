@@ -0,0 +1,21 @@
+package av1
+
+import "fmt"
+
+// CodecString - sub-parameter for MIME type "codecs" parameter like av01.0.04M.08 where av01 is sampleEntry.
+// Defined in AV1 Codec ISO Media File Format Binding Section 5.
+// The optional color parameters are not included.
+func CodecString(sampleEntry string, c *CodecConfRec) string {
+	tier := "M"
+	if c.SeqTier0 == 1 {
+		tier = "H"
+	}
+	bitDepth := 8
+	switch {
+	case c.HighBitdepth == 1 && c.TwelveBit == 1:
+		bitDepth = 12
+	case c.HighBitdepth == 1:
+		bitDepth = 10
+	}
+	return fmt.Sprintf("%s.%d.%02d%s.%02d", sampleEntry, c.SeqProfile, c.SeqLevelIdx0, tier, bitDepth)
+}
@@ -0,0 +1,190 @@
+package av1
+
+import (
+	"bytes"
+	"errors"
+	"fmt"
+
+	"github.com/Eyevinn/mp4ff/bits"
+)
+
+// OBUType - AV1 OBU type (AV1 Section 6.2.2)
+type OBUType byte
+
+// OBU types
+const (
+	OBUSequenceHeader       OBUType = 1
+	OBUTemporalDelimiter    OBUType = 2
+	OBUFrameHeader          OBUType = 3
+	OBUTileGroup            OBUType = 4
+	OBUMetadata             OBUType = 5
+	OBUFrame                OBUType = 6
+	OBURedundantFrameHeader OBUType = 7
+	OBUTileList             OBUType = 8
+	OBUPadding              OBUType = 15
+)
+
+// ErrNoSequenceHeader - no sequence header OBU found
+var ErrNoSequenceHeader = errors.New("no sequence header OBU")
+
+// OBUHeader - header of an OBU including the size from obu_size or the available data (AV1 Section 5.3)
+type OBUHeader struct {
+	Type         OBUType
+	HasExtension bool
+	HasSizeField bool
+	TemporalID   byte
+	SpatialID    byte
+	HeaderSize   int // size of obu_header and obu_size
+	PayloadSize  int
+}
+
+// ParseOBUHeader - parse OBU header at start of data.
+// Without obu_size, the payload is the rest of data.
+func ParseOBUHeader(data []byte) (OBUHeader, error) {
+	var h OBUHeader
+	if len(data) < 1 {
+		return h, fmt.Errorf("no OBU header")
+	}
+	if data[0]&0x80 != 0 {
+		return h, fmt.Errorf("obu_forbidden_bit set")
+	}
+	h.Type = OBUType((data[0] >> 3) & 0x0f)
+	h.HasExtension = data[0]&0x04 != 0
+	h.HasSizeField = data[0]&0x02 != 0
+	pos := 1
+	if h.HasExtension {
+		if len(data) < 2 {
+			return h, fmt.Errorf("no OBU extension header")
+		}
+		h.TemporalID = data[1] >> 5
+		h.SpatialID = (data[1] >> 3) & 0x03
+		pos++
+	}
+	if !h.HasSizeField {
+		h.HeaderSize = pos
+		h.PayloadSize = len(data) - pos
+		return h, nil
+	}
+	var size uint64
+	for i := 0; ; i++ {
+		if i == 8 {
+			return h, fmt.Errorf("obu_size longer than 8 bytes")
+		}
+		if pos >= len(data) {
+			return h, fmt.Errorf("obu_size not complete")
+		}
+		b := data[pos]
+		pos++
+		size |= uint64(b&0x7f) << (7 * i)
+		if b&0x80 == 0 {
+			break
+		}
+	}
+	if size > uint64(len(data)-pos) {
+		return h, fmt.Errorf("obu_size %d larger than available %d bytes", size, len(data)-pos)
+	}
+	h.HeaderSize = pos
+	h.PayloadSize = int(size)
+	return h, nil
+}
+
+// SequenceHeader - start of sequence header OBU up to the maximum frame size (AV1 Section 5.5.1)
+type SequenceHeader struct {
+	SeqProfile                byte
+	StillPicture              bool
+	ReducedStillPictureHeader bool
+	SeqLevelIdx0              byte
+	SeqTier0                  byte
+	MaxFrameWidth             uint32
+	MaxFrameHeight            uint32
+}
+
+// ParseSequenceHeader - parse sequence header OBU payload up to max_frame_height_minus_1
+func ParseSequenceHeader(payload []byte) (*SequenceHeader, error) {
+	r := bits.NewReader(bytes.NewReader(payload))
+	sh := &SequenceHeader{}
+	sh.SeqProfile = byte(r.Read(3))
+	sh.StillPicture = r.ReadFlag()
+	sh.ReducedStillPictureHeader = r.ReadFlag()
+	if sh.ReducedStillPictureHeader {
+		sh.SeqLevelIdx0 = byte(r.Read(5))
+	} else {
+		decoderModelInfoPresent := false
+		bufferDelayLength := 0
+		if timingInfoPresent := r.ReadFlag(); timingInfoPresent {
+			_ = r.Read(32) // num_units_in_display_tick
+			_ = r.Read(32) // time_scale
+			if equalPictureInterval := r.ReadFlag(); equalPictureInterval {
+				readUvlc(r) // num_ticks_per_picture_minus_1
+			}
+			decoderModelInfoPresent = r.ReadFlag()
+			if decoderModelInfoPresent {
+				bufferDelayLength = int(r.Read(5)) + 1
+				_ = r.Read(32) // num_units_in_decoding_tick
+				_ = r.Read(5)  // buffer_removal_time_length_minus_1
+				_ = r.Read(5)  // frame_presentation_time_length_minus_1
+			}
+		}
+		initialDisplayDelayPresent := r.ReadFlag()
+		operatingPointsCnt := int(r.Read(5)) + 1
+		for i := 0; i < operatingPointsCnt; i++ {
+			_ = r.Read(12) // operating_point_idc
+			seqLevelIdx := byte(r.Read(5))
+			var seqTier byte
+			if seqLevelIdx > 7 {
+				seqTier = byte(r.Read(1))
+			}
+			if i == 0 {
+				sh.SeqLevelIdx0, sh.SeqTier0 = seqLevelIdx, seqTier
+			}
+			if decoderModelInfoPresent {
+				if decoderModelPresent := r.ReadFlag(); decoderModelPresent {
+					_ = r.Read(bufferDelayLength) // decoder_buffer_delay
+					_ = r.Read(bufferDelayLength) // encoder_buffer_delay
+					_ = r.Read(1)                 // low_delay_mode_flag
+				}
+			}
+			if initialDisplayDelayPresent {
+				if r.ReadFlag() {
+					_ = r.Read(4) // initial_display_delay_minus_1
+				}
+			}
+		}
+	}
+	frameWidthBits := int(r.Read(4)) + 1
+	frameHeightBits := int(r.Read(4)) + 1
+	sh.MaxFrameWidth = uint32(r.Read(frameWidthBits)) + 1
+	sh.MaxFrameHeight = uint32(r.Read(frameHeightBits)) + 1
+	if err := r.AccError(); err != nil {
+		return nil, fmt.Errorf("sequence header: %w", err)
+	}
+	return sh, nil
+}
+
+// readUvlc - read variable length unsigned value (AV1 Section 4.10.3)
+func readUvlc(r *bits.Reader) uint64 {
+	leadingZeros := 0
+	for !r.ReadFlag() {
+		if r.AccError() != nil || leadingZeros >= 32 {
+			return 0
+		}
+		leadingZeros++
+	}
+	return uint64(r.Read(leadingZeros)) + (1 << leadingZeros) - 1
+}
+
+// FindSequenceHeader - find and parse the first sequence header OBU in data with OBUs with obu_size
+func FindSequenceHeader(data []byte) (*SequenceHeader, error) {
+	for pos := 0; pos < len(data); {
+		h, err := ParseOBUHeader(data[pos:])
+		if err != nil {
+			return nil, err
+		}
+		payloadStart := pos + h.HeaderSize
+		if h.Type == OBUSequenceHeader {
+			return ParseSequenceHeader(data[payloadStart : payloadStart+h.PayloadSize])
+		}
+		pos = payloadStart + h.PayloadSize
+	}
+	return nil, ErrNoSequenceHeader
+}
@@ -0,0 +1,60 @@
+package av1
+
+import (
+	"encoding/hex"
+	"errors"
+	"testing"
+
+	"github.com/go-test/deep"
+)
+
+func TestFindSequenceHeader(t *testing.T) {
+	data, _ := hex.DecodeString(configOBUs)
+	h, err := ParseOBUHeader(data)
+	if err != nil {
+		t.Fatal(err)
+	}
+	wantedHdr := OBUHeader{Type: OBUSequenceHeader, HasSizeField: true, HeaderSize: 2, PayloadSize: 11}
+	if diff := deep.Equal(h, wantedHdr); diff != nil {
+		t.Error(diff)
+	}
+	sh, err := FindSequenceHeader(data)
+	if err != nil {
+		t.Fatal(err)
+	}
+	wanted := &SequenceHeader{SeqLevelIdx0: 9, MaxFrameWidth: 1920, MaxFrameHeight: 1080}
+	if diff := deep.Equal(sh, wanted); diff != nil {
+		t.Error(diff)
+	}
+	// Temporal delimiter OBU before sequence header
+	sh, err = FindSequenceHeader(append([]byte{0x12, 0x00}, data...))
+	if err != nil {
+		t.Fatal(err)
+	}
+	if diff := deep.Equal(sh, wanted); diff != nil {
+		t.Error(diff)
+	}
+	_, err = FindSequenceHeader([]byte{0x12, 0x00})
+	if !errors.Is(err, ErrNoSequenceHeader) {
+		t.Errorf("got error %v instead of ErrNoSequenceHeader", err)
+	}
+	_, err = FindSequenceHeader(data[:8])
+	if err == nil {
+		t.Error("expected error for truncated OBU")
+	}
+}
+
+func TestCodecString(t *testing.T) {
+	byteData, _ := hex.DecodeString(av1DecoderConfigRecord)
+	c, err := DecodeAV1CodecConfRec(byteData)
+	if err != nil {
+		t.Fatal(err)
+	}
+	if got := CodecString("av01", &c); got != "av01.0.09M.10" {
+		t.Errorf("got codec string %s instead of av01.0.09M.10", got)
+	}
+	c = CodecConfRec{SeqProfile: 2, SeqLevelIdx0: 4, HighBitdepth: 1, TwelveBit: 1, SeqTier0: 1}
+	if got := CodecString("av01", &c); got != "av01.2.04H.12" {
+		t.Errorf("got codec string %s instead of av01.2.04H.12", got)
+	}
+}
@@ -0,0 +1,88 @@
+package avc
+
+import "fmt"
+
+// AccessUnit - NAL units of one primary coded picture given as indices into a list of NAL units
+type AccessUnit struct {
+	NaluIndices []int
+	IsIDR       bool
+	NalRefIDC   byte         // nal_ref_idc of the first slice
+	SliceHeader *SliceHeader // header of the first slice
+}
+
+// SplitIntoAccessUnits - group NAL units in decoding order into access units (ISO/IEC 14496-10 Section 7.4.1.2.3).
+// A new access unit starts at an AUD, SPS, PPS, SEI, or NAL unit type 14-18 after a slice,
+// and at a slice with first_mb_in_slice equal to 0 or with a slice header that shows that it
+// belongs to a new picture (Section 7.4.1.2.4), e.g. by frame_num, pic_parameter_set_id, or field_pic_flag.
+// SPS and PPS NAL units are parsed and added to spsMap and ppsMap.
+// NAL units after the last slice that would start a new access unit are dropped.
+func SplitIntoAccessUnits(nalus [][]byte, spsMap map[uint32]*SPS, ppsMap map[uint32]*PPS) ([]AccessUnit, error) {
+	var aus []AccessUnit
+	var pending []int // NAL units before the first slice of the next access unit
+	for i, nalu := range nalus {
+		if len(nalu) == 0 {
+			continue
+		}
+		naluType := GetNaluType(nalu[0])
+		switch naluType {
+		case NALU_IDR, NALU_NON_IDR:
+			sh, err := ParseSliceHeader(nalu, spsMap, ppsMap)
+			if err != nil {
+				return nil, fmt.Errorf("NAL unit %d: %w", i, err)
+			}
+			isIDR := naluType == NALU_IDR
+			nalRefIDC := (nalu[0] >> 5) & 0x3
+			if len(aus) == 0 || len(pending) > 0 || sh.FirstMBInSlice == 0 ||
+				isNewPicture(&aus[len(aus)-1], sh, isIDR, nalRefIDC) {
+				aus = append(aus, AccessUnit{NaluIndices: pending, IsIDR: isIDR, NalRefIDC: nalRefIDC, SliceHeader: sh})
+				pending = nil
+			}
+			au := &aus[len(aus)-1]
+			au.NaluIndices = append(au.NaluIndices, i)
+			continue
+		case NALU_SPS:
+			sps, err := ParseSPSNALUnit(nalu, false)
+			if err != nil {
+				return nil, fmt.Errorf("NAL unit %d: %w", i, err)
+			}
+			spsMap[sps.ParameterID] = sps
+		case NALU_PPS:
+			pps, err := ParsePPSNALUnit(nalu, spsMap)
+			if err != nil {
+				return nil, fmt.Errorf("NAL unit %d: %w", i, err)
+			}
+			ppsMap[pps.PicParameterSetID] = pps
+		}
+		if len(aus) == 0 || len(pending) > 0 || startsAccessUnit(naluType) {
+			pending = append(pending, i)
+			continue
+		}
+		au := &aus[len(aus)-1]
+		au.NaluIndices = append(au.NaluIndices, i)
+	}
+	return aus, nil
+}
+
+// startsAccessUnit - NAL unit type that starts a new access unit if it comes after a slice
+func startsAccessUnit(naluType NaluType) bool {
+	switch naluType {
+	case NALU_AUD, NALU_SPS, NALU_PPS, NALU_SEI:
+		return true
+	}
+	return 14 <= naluType && naluType <= 18
+}
+
+// isNewPicture - slice header differs from the first slice of au so that it belongs to a new picture
+func isNewPicture(au *AccessUnit, sh *SliceHeader, isIDR bool, nalRefIDC byte) bool {
+	prev := au.SliceHeader
+	return sh.FrameNum != prev.FrameNum ||
+		sh.PicParamID != prev.PicParamID ||
+		sh.FieldPicFlag != prev.FieldPicFlag ||
+		sh.BottomFieldFlag != prev.BottomFieldFlag ||
+		(nalRefIDC == 0) != (au.NalRefIDC == 0) ||
+		isIDR != au.IsIDR ||
+		(isIDR && sh.IDRPicID != prev.IDRPicID) ||
+		sh.PicOrderCntLsb != prev.PicOrderCntLsb ||
+		sh.DeltaPicOrderCntBottom != prev.DeltaPicOrderCntBottom ||
+		sh.DeltaPicOrderCnt != prev.DeltaPicOrderCnt
+}
@@ -0,0 +1,69 @@
+package avc
+
+import (
+	"os"
+	"testing"
+
+	"github.com/go-test/deep"
+)
+
+func TestSplitIntoAccessUnits(t *testing.T) {
+	data, err := os.ReadFile("../cmd/mp4ff-nallister/testdata/4pics.264")
+	if err != nil {
+		t.Fatal(err)
+	}
+	// AUD, SPS, PPS, SEI, IDR, and then AUD, PPS, SEI, and non-IDR slice three times
+	nalus := ExtractNalusFromByteStream(data)
+	var noAUDs, slices [][]byte
+	for _, nalu := range nalus {
+		switch GetNaluType(nalu[0]) {
+		case NALU_AUD:
+		case NALU_IDR, NALU_NON_IDR:
+			slices = append(slices, nalu)
+			noAUDs = append(noAUDs, nalu)
+		default:
+			noAUDs = append(noAUDs, nalu)
+		}
+	}
+	spsMap := make(map[uint32]*SPS)
+	ppsMap := make(map[uint32]*PPS)
+	testCases := []struct {
+		desc          string
+		nalus         [][]byte
+		wantedIndices [][]int
+	}{
+		{desc: "with AUDs", nalus: nalus,
+			wantedIndices: [][]int{{0, 1, 2, 3, 4}, {5, 6, 7, 8}, {9, 10, 11, 12}, {13, 14, 15, 16}}},
+		{desc: "without AUDs", nalus: noAUDs,
+			wantedIndices: [][]int{{0, 1, 2, 3}, {4, 5, 6}, {7, 8, 9}, {10, 11, 12}}},
+		{desc: "only slices", nalus: slices,
+			wantedIndices: [][]int{{0}, {1}, {2}, {3}}},
+		{desc: "no slice at end", nalus: nalus[:15],
+			wantedIndices: [][]int{{0, 1, 2, 3, 4}, {5, 6, 7, 8}, {9, 10, 11, 12}}},
+	}
+	for _, tc := range testCases {
+		t.Run(tc.desc, func(t *testing.T) {
+			aus, err := SplitIntoAccessUnits(tc.nalus, spsMap, ppsMap)
+			if err != nil {
+				t.Fatal(err)
+			}
+			var gotIndices [][]int
+			for i, au := range aus {
+				gotIndices = append(gotIndices, au.NaluIndices)
+				if au.IsIDR != (i == 0) {
+					t.Errorf("access unit %d: got IsIDR %t", i, au.IsIDR)
+				}
+				if au.SliceHeader == nil || au.SliceHeader.FrameNum != uint32(i) {
+					t.Errorf("access unit %d: bad first slice header", i)
+				}
+			}
+			if diff := deep.Equal(gotIndices, tc.wantedIndices); diff != nil {
+				t.Error(diff)
+			}
+		})
+	}
+	_, err = SplitIntoAccessUnits(slices, make(map[uint32]*SPS), make(map[uint32]*PPS))
+	if err == nil {
+		t.Error("expected error for slices without parameter sets")
+	}
+}
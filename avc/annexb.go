@@ -38,6 +38,69 @@ func ExtractNalusFromByteStream(data []byte) [][]byte {
 	return nalus
 }
 
+// ByteStreamParser extracts NALUs without startcode from ByteStream data arriving in chunks.
+// A NALU is returned as soon as the next start code has been found.
+// This parser is codec agnostic.
+type ByteStreamParser struct {
+	buf     []byte // unreturned data. Starts with current NALU if started
+	started bool   // a start code has been found
+	scanPos int    // position in buf to continue start code search from
+}
+
+// NewByteStreamParser returns a parser for ByteStream data arriving in chunks.
+func NewByteStreamParser() *ByteStreamParser {
+	return &ByteStreamParser{}
+}
+
+// Push adds a chunk of data and returns the NALUs that were completed by it.
+// Start codes may be split between chunks. Data before the first start code is dropped.
+func (p *ByteStreamParser) Push(data []byte) [][]byte {
+	p.buf = append(p.buf, data...)
+	n := len(p.buf)
+	var nalus [][]byte
+	naluStart := 0
+	i := p.scanPos
+	for ; i < n-2; i++ {
+		if p.buf[i] == 0 && p.buf[i+1] == 0 && p.buf[i+2] == 1 {
+			if p.started {
+				naluEnd := i
+				for naluEnd > naluStart && p.buf[naluEnd-1] == 0 {
+					naluEnd-- // Remove zeros from end of NAL unit
+				}
+				if naluEnd > naluStart {
+					nalus = append(nalus, extractSlice(p.buf, naluStart, naluEnd))
+				}
+			}
+			p.started = true
+			naluStart = i + 3
+			i += 2
+		}
+	}
+	keepFrom := naluStart
+	if !p.started {
+		// Only keep the last bytes, since they may be the beginning of a start code
+		keepFrom = n - 2
+		if keepFrom < 0 {
+			keepFrom = 0
+		}
+	}
+	p.scanPos = i - keepFrom
+	p.buf = p.buf[:copy(p.buf, p.buf[keepFrom:])]
+	return nalus
+}
+
+// Flush returns the last NALU (nil if none) and resets the parser.
+func (p *ByteStreamParser) Flush() []byte {
+	var nalu []byte
+	if p.started && len(p.buf) > 0 {
+		nalu = extractSlice(p.buf, 0, len(p.buf))
+	}
+	p.buf = p.buf[:0]
+	p.started = false
+	p.scanPos = 0
+	return nalu
+}
+
 func extractSlice(data []byte, start, stop int) []byte {
 	sl := make([]byte, stop-start)
 	_ = copy(sl, data[start:stop])
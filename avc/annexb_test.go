@@ -50,6 +50,28 @@ func TestNaluExtraction(t *testing.T) {
 	}
 }
 
+func TestByteStreamParser(t *testing.T) {
+	stream := []byte{0, 0, 0, 1, 0x67, 0xaa, 0xbb, 0, 0, 1, 0x68, 0xcc, 0, 0, 0, 1, 0x65, 0xdd, 0xee, 0xff}
+	wanted := [][]byte{{0x67, 0xaa, 0xbb}, {0x68, 0xcc}, {0x65, 0xdd, 0xee, 0xff}}
+	if diff := deep.Equal(ExtractNalusFromByteStream(stream), wanted); diff != nil {
+		t.Errorf("ExtractNalusFromByteStream: %v", diff)
+	}
+	// Split the stream into three chunks at different places, including inside start codes
+	for _, cuts := range [][2]int{{2, 9}, {5, 14}, {8, 13}, {1, 17}} {
+		p := NewByteStreamParser()
+		var got [][]byte
+		got = append(got, p.Push(stream[:cuts[0]])...)
+		got = append(got, p.Push(stream[cuts[0]:cuts[1]])...)
+		got = append(got, p.Push(stream[cuts[1]:])...)
+		if last := p.Flush(); last != nil {
+			got = append(got, last)
+		}
+		if diff := deep.Equal(got, wanted); diff != nil {
+			t.Errorf("cuts %v: %v", cuts, diff)
+		}
+	}
+}
+
 func TestByteStreamToNaluSampleConversion(t *testing.T) {
 	testCases := []struct {
 		name   string
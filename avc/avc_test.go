@@ -161,3 +161,35 @@ func TestIsVideoNaluType(t *testing.T) {
 		})
 	}
 }
+
+func TestGetNalusFromSampleWithLengthSize(t *testing.T) {
+	testCases := []struct {
+		name       string
+		lengthSize int
+		input      []byte
+		wanted     [][]byte
+		wantErr    bool
+	}{
+		{"1 byte", 1, []byte{2, 9, 2, 3, 7, 5, 4}, [][]byte{{9, 2}, {7, 5, 4}}, false},
+		{"2 bytes", 2, []byte{0, 2, 9, 2, 0, 3, 7, 5, 4}, [][]byte{{9, 2}, {7, 5, 4}}, false},
+		{"4 bytes", 4, []byte{0, 0, 0, 2, 9, 2, 0, 0, 0, 3, 7, 5, 4}, [][]byte{{9, 2}, {7, 5, 4}}, false},
+		{"bad length", 2, []byte{0, 2, 9, 2, 0, 4, 7, 5, 4}, nil, true},
+		{"bad length size", 3, []byte{0, 0, 2, 9, 2}, nil, true},
+	}
+	for _, tc := range testCases {
+		got, err := GetNalusFromSampleWithLengthSize(tc.input, tc.lengthSize)
+		if tc.wantErr {
+			if err == nil {
+				t.Errorf("%s: expected error", tc.name)
+			}
+			continue
+		}
+		if err != nil {
+			t.Errorf("%s: %v", tc.name, err)
+			continue
+		}
+		if diff := deep.Equal(got, tc.wanted); diff != nil {
+			t.Errorf("%s: %v", tc.name, diff)
+		}
+	}
+}
@@ -3,6 +3,7 @@ package avc
 import (
 	"bytes"
 	"encoding/hex"
+	"errors"
 	"os"
 	"testing"
 
@@ -22,6 +23,7 @@ func TestAvcDecoderConfigRecord(t *testing.T) {
 		AVCProfileIndication: 100,
 		ProfileCompatibility: 0,
 		AVCLevelIndication:   30,
+		LengthSize:           4,
 		SPSnalus:             [][]byte{spsBytes},
 		PPSnalus:             [][]byte{ppsBytes},
 		ChromaFormat:         1,
@@ -58,6 +60,7 @@ func TestAvcDecoderConfigRecordWithExtraBytes(t *testing.T) {
 		AVCProfileIndication: 77,
 		ProfileCompatibility: 64,
 		AVCLevelIndication:   41,
+		LengthSize:           4,
 		SPSnalus:             [][]byte{spsBytes},
 		PPSnalus:             [][]byte{ppsBytes},
 		ChromaFormat:         0,
@@ -96,6 +99,7 @@ func TestAvcDecoderConfigRecordWithExtraBytesProfileHigh(t *testing.T) {
 		AVCProfileIndication: 100,
 		ProfileCompatibility: 0,
 		AVCLevelIndication:   41,
+		LengthSize:           4,
 		SPSnalus:             [][]byte{spsBytes},
 		PPSnalus:             [][]byte{ppsBytes},
 		ChromaFormat:         0,
@@ -139,3 +143,93 @@ func TestCreateAVCDecConfRec(t *testing.T) {
 		t.Error("Error creating AVCDecoderConfigurationRecord")
 	}
 }
+
+func TestAvcDecoderConfigRecordLengthSize(t *testing.T) {
+	byteData, _ := hex.DecodeString(avcDecoderConfigRecord)
+	byteData[4] = 0xfd // lengthSizeMinusOne = 1
+	got, err := DecodeAVCDecConfRec(byteData)
+	if err != nil {
+		t.Fatal(err)
+	}
+	if got.LengthSize != 2 {
+		t.Errorf("got LengthSize %d instead of 2", got.LengthSize)
+	}
+	enc := bytes.Buffer{}
+	err = got.Encode(&enc)
+	if err != nil {
+		t.Fatal(err)
+	}
+	if !bytes.Equal(enc.Bytes(), byteData) {
+		t.Error("encoded AVCDecoderConfigurationRecord differs from input")
+	}
+	got.LengthSize = 0 // The zero value gives 4 byte length fields
+	enc.Reset()
+	if err = got.Encode(&enc); err != nil {
+		t.Fatal(err)
+	}
+	if enc.Bytes()[4] != 0xff {
+		t.Errorf("got lengthSizeMinusOne byte %02x instead of ff", enc.Bytes()[4])
+	}
+	got.LengthSize = 3
+	if err = got.Encode(&enc); !errors.Is(err, ErrLengthSize) {
+		t.Errorf("got error %v instead of ErrLengthSize", err)
+	}
+	byteData[4] = 0xfe // lengthSizeMinusOne = 2 is not allowed
+	_, err = DecodeAVCDecConfRec(byteData)
+	if !errors.Is(err, ErrLengthSize) {
+		t.Errorf("got error %v instead of ErrLengthSize", err)
+	}
+}
+
+func TestParameterSetMaps(t *testing.T) {
+	spsBytes, _ := hex.DecodeString(sps)
+	ppsBytes, _ := hex.DecodeString(pps)
+	spsMap := make(map[uint32]*SPS, 1)
+	parsedSPS, err := ParseSPSNALUnit(spsBytes, true)
+	if err != nil {
+		t.Fatal(err)
+	}
+	spsMap[parsedSPS.ParameterID] = parsedSPS
+	pps1, err := ParsePPSNALUnit(ppsBytes, spsMap)
+	if err != nil {
+		t.Fatal(err)
+	}
+	pps1.PicParameterSetID = 1
+	pps1Bytes, err := EncodePPSNALUnit(pps1)
+	if err != nil {
+		t.Fatal(err)
+	}
+	adcr, err := CreateAVCDecConfRec([][]byte{spsBytes}, [][]byte{ppsBytes, pps1Bytes}, true)
+	if err != nil {
+		t.Fatal(err)
+	}
+	enc := bytes.Buffer{}
+	err = adcr.Encode(&enc)
+	if err != nil {
+		t.Fatal(err)
+	}
+	decAdcr, err := DecodeAVCDecConfRec(enc.Bytes())
+	if err != nil {
+		t.Fatal(err)
+	}
+	gotSPSMap, gotPPSMap, err := decAdcr.ParameterSetMaps()
+	if err != nil {
+		t.Fatal(err)
+	}
+	if len(gotSPSMap) != 1 || gotSPSMap[0] == nil {
+		t.Errorf("expected one SPS with id 0, got %v", gotSPSMap)
+	}
+	if len(gotPPSMap) != 2 {
+		t.Fatalf("expected 2 PPS, got %d", len(gotPPSMap))
+	}
+	for _, id := range []uint32{0, 1} {
+		gotPPS, ok := gotPPSMap[id]
+		if !ok {
+			t.Errorf("PPS with id %d not found", id)
+			continue
+		}
+		if gotPPS.PicParameterSetID != id {
+			t.Errorf("PPS for id %d has id %d", id, gotPPS.PicParameterSetID)
+		}
+	}
+}
@@ -12,7 +12,7 @@ import (
 // AVC parsing errors
 var (
 	ErrCannotParseAVCExtension = errors.New("cannot parse SPS extensions")
-	ErrLengthSize              = errors.New("can only handle 4byte NAL length size")
+	ErrLengthSize              = errors.New("can only handle 1, 2, or 4 byte NAL length size")
 )
 
 // DecConfRec - AVCDecoderConfigurationRecord
@@ -20,6 +20,7 @@ type DecConfRec struct {
 	AVCProfileIndication byte
 	ProfileCompatibility byte
 	AVCLevelIndication   byte
+	LengthSize           byte // NAL unit length field size in bytes (1, 2, or 4). 0 means 4
 	SPSnalus             [][]byte
 	PPSnalus             [][]byte
 	ChromaFormat         byte
@@ -45,6 +46,7 @@ func CreateAVCDecConfRec(spsNalus [][]byte, ppsNalus [][]byte, includePS bool) (
 		AVCProfileIndication: byte(sps.Profile),
 		ProfileCompatibility: byte(sps.ProfileCompatibility),
 		AVCLevelIndication:   byte(sps.Level),
+		LengthSize:           4,
 		SPSnalus:             nil,
 		PPSnalus:             nil,
 		ChromaFormat:         1,
@@ -75,8 +77,8 @@ func DecodeAVCDecConfRec(data []byte) (DecConfRec, error) {
 	AVCProfileIndication := data[1]
 	ProfileCompatibility := data[2]
 	AVCLevelIndication := data[3]
-	LengthSizeMinus1 := data[4] & 0x03 // The first 5 bits are 1
-	if LengthSizeMinus1 != 0x3 {
+	LengthSizeMinusOne := data[4] & 0x03 // The first 6 bits are 1
+	if LengthSizeMinusOne == 0x2 {
 		return DecConfRec{}, ErrLengthSize
 	}
 	numSPS := data[5] & 0x1f // 5 bits following 3 reserved bits
@@ -127,6 +129,7 @@ func DecodeAVCDecConfRec(data []byte) (DecConfRec, error) {
 		AVCProfileIndication: AVCProfileIndication,
 		ProfileCompatibility: ProfileCompatibility,
 		AVCLevelIndication:   AVCLevelIndication,
+		LengthSize:           LengthSizeMinusOne + 1,
 		SPSnalus:             spsNALUs,
 		PPSnalus:             ppsNALUs,
 	}
@@ -166,6 +169,28 @@ func DecodeAVCDecConfRec(data []byte) (DecConfRec, error) {
 	return adcr, nil
 }
 
+// ParameterSetMaps - parse all SPS and PPS NAL units and return them in maps keyed by their IDs.
+// The maps can be used as input to ParseSliceHeader.
+func (a *DecConfRec) ParameterSetMaps() (spsMap map[uint32]*SPS, ppsMap map[uint32]*PPS, err error) {
+	spsMap = make(map[uint32]*SPS, len(a.SPSnalus))
+	for _, nalu := range a.SPSnalus {
+		sps, err := ParseSPSNALUnit(nalu, true)
+		if err != nil {
+			return nil, nil, err
+		}
+		spsMap[sps.ParameterID] = sps
+	}
+	ppsMap = make(map[uint32]*PPS, len(a.PPSnalus))
+	for _, nalu := range a.PPSnalus {
+		pps, err := ParsePPSNALUnit(nalu, spsMap)
+		if err != nil {
+			return nil, nil, err
+		}
+		ppsMap[pps.PicParameterSetID] = pps
+	}
+	return spsMap, ppsMap, nil
+}
+
 // Size - total size in bytes
 func (a *DecConfRec) Size() uint64 {
 	totalSize := 7
@@ -187,6 +212,14 @@ func (a *DecConfRec) Size() uint64 {
 	return uint64(totalSize)
 }
 
+// NaluLengthSize - size of NAL unit length fields in bytes. A LengthSize of 0 gives 4
+func (a *DecConfRec) NaluLengthSize() int {
+	if a.LengthSize == 0 {
+		return 4
+	}
+	return int(a.LengthSize)
+}
+
 // Encode - write box to w
 func (a *DecConfRec) Encode(w io.Writer) error {
 	sw := bits.NewFixedSliceWriter(int(a.Size()))
@@ -206,7 +239,11 @@ func (a *DecConfRec) EncodeSW(sw bits.SliceWriter) error {
 	sw.WriteUint8(a.AVCProfileIndication)
 	sw.WriteUint8(a.ProfileCompatibility)
 	sw.WriteUint8(a.AVCLevelIndication)
-	sw.WriteUint8(0xff) // Set length to 4
+	lengthSize := a.NaluLengthSize()
+	if lengthSize != 1 && lengthSize != 2 && lengthSize != 4 {
+		return fmt.Errorf("%w: %d", ErrLengthSize, lengthSize)
+	}
+	sw.WriteUint8(0xfc | byte(lengthSize-1))
 
 	var nrSPS = byte(len(a.SPSnalus)) | 0xe0 // Added reserved 3 bits
 	sw.WriteUint8(nrSPS)
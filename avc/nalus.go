@@ -7,16 +7,35 @@ import (
 
 // GetNalusFromSample - get nalus by following 4 byte length fields
 func GetNalusFromSample(sample []byte) ([][]byte, error) {
+	return GetNalusFromSampleWithLengthSize(sample, 4)
+}
+
+// GetNalusFromSampleWithLengthSize - get nalus by following 1, 2, or 4 byte length fields.
+// The length size is given by DecConfRec.NaluLengthSize().
+func GetNalusFromSampleWithLengthSize(sample []byte, lengthSize int) ([][]byte, error) {
+	switch lengthSize {
+	case 1, 2, 4:
+	default:
+		return nil, fmt.Errorf("%w: %d", ErrLengthSize, lengthSize)
+	}
 	length := len(sample)
-	if length < 4 {
-		return nil, fmt.Errorf("less than 4 bytes, No NALUs")
+	if length < lengthSize {
+		return nil, fmt.Errorf("less than %d bytes, No NALUs", lengthSize)
 	}
 	naluList := make([][]byte, 0, 2)
-	var pos uint32 = 0
-	for pos < uint32(length-4) {
-		naluLength := binary.BigEndian.Uint32(sample[pos : pos+4])
-		pos += 4
-		if int(pos+naluLength) > len(sample) {
+	pos := 0
+	for pos < length-lengthSize {
+		var naluLength int
+		switch lengthSize {
+		case 1:
+			naluLength = int(sample[pos])
+		case 2:
+			naluLength = int(binary.BigEndian.Uint16(sample[pos : pos+2]))
+		default:
+			naluLength = int(binary.BigEndian.Uint32(sample[pos : pos+4]))
+		}
+		pos += lengthSize
+		if pos+naluLength > length {
 			return nil, fmt.Errorf("NALU length fields are bad. Not video?")
 		}
 		naluList = append(naluList, sample[pos:pos+naluLength])
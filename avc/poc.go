@@ -0,0 +1,145 @@
+package avc
+
+// POCCalculator - compute picture order count (POC) according to ISO/IEC 14496-10 Section 8.2.1.
+// The state from previous pictures is kept, so Compute must be called for every picture
+// in decoding order.
+type POCCalculator struct {
+	prevPicOrderCntMsb int
+	prevPicOrderCntLsb int
+	prevFrameNumOffset int
+	prevFrameNum       uint32
+}
+
+// NewPOCCalculator - create a new POCCalculator
+func NewPOCCalculator() *POCCalculator {
+	return &POCCalculator{}
+}
+
+// Compute - compute POC for a picture given its first slice header and nal_ref_idc.
+// The returned POC is Min(TopFieldOrderCnt, BottomFieldOrderCnt) for a frame,
+// and the field order count for a field.
+// For a picture with memory_management_control_operation 5, the POC is the value before
+// the reset that is applied once the picture is decoded.
+func (p *POCCalculator) Compute(sh *SliceHeader, sps *SPS, isIDR bool, nalRefIDC byte) int {
+	var topFieldOrderCnt, bottomFieldOrderCnt int
+	isRef := nalRefIDC != 0
+	maxFrameNum := 1 << (sps.Log2MaxFrameNumMinus4 + 4)
+
+	switch sps.PicOrderCntType {
+	case 0: // 8.2.1.1
+		if isIDR {
+			p.prevPicOrderCntMsb, p.prevPicOrderCntLsb = 0, 0
+		}
+		maxPicOrderCntLsb := 1 << (sps.Log2MaxPicOrderCntLsbMinus4 + 4)
+		picOrderCntLsb := int(sh.PicOrderCntLsb)
+		var picOrderCntMsb int
+		switch {
+		case picOrderCntLsb < p.prevPicOrderCntLsb && p.prevPicOrderCntLsb-picOrderCntLsb >= maxPicOrderCntLsb/2:
+			picOrderCntMsb = p.prevPicOrderCntMsb + maxPicOrderCntLsb
+		case picOrderCntLsb > p.prevPicOrderCntLsb && picOrderCntLsb-p.prevPicOrderCntLsb > maxPicOrderCntLsb/2:
+			picOrderCntMsb = p.prevPicOrderCntMsb - maxPicOrderCntLsb
+		default:
+			picOrderCntMsb = p.prevPicOrderCntMsb
+		}
+		topFieldOrderCnt = picOrderCntMsb + picOrderCntLsb
+		bottomFieldOrderCnt = topFieldOrderCnt + int(sh.DeltaPicOrderCntBottom)
+		if sh.FieldPicFlag {
+			bottomFieldOrderCnt = topFieldOrderCnt
+		}
+		if isRef {
+			p.prevPicOrderCntMsb, p.prevPicOrderCntLsb = picOrderCntMsb, picOrderCntLsb
+			if sh.HasMMCO5 {
+				p.prevPicOrderCntMsb, p.prevPicOrderCntLsb = 0, 0
+				if !sh.BottomFieldFlag {
+					p.prevPicOrderCntLsb = topFieldOrderCnt - minPOC(sh, topFieldOrderCnt, bottomFieldOrderCnt)
+				}
+			}
+		}
+	case 1: // 8.2.1.2
+		frameNumOffset := p.frameNumOffset(sh, isIDR, maxFrameNum)
+		nrInCycle := len(sps.RefFramesInPicOrderCntCycle)
+		absFrameNum := 0
+		if nrInCycle != 0 {
+			absFrameNum = frameNumOffset + int(sh.FrameNum)
+		}
+		if !isRef && absFrameNum > 0 {
+			absFrameNum--
+		}
+		expectedPicOrderCnt := 0
+		if absFrameNum > 0 {
+			expectedDeltaPerPicOrderCntCycle := 0
+			for _, offset := range sps.RefFramesInPicOrderCntCycle {
+				expectedDeltaPerPicOrderCntCycle += offset
+			}
+			picOrderCntCycleCnt := (absFrameNum - 1) / nrInCycle
+			frameNumInPicOrderCntCycle := (absFrameNum - 1) % nrInCycle
+			expectedPicOrderCnt = picOrderCntCycleCnt * expectedDeltaPerPicOrderCntCycle
+			for i := 0; i <= frameNumInPicOrderCntCycle; i++ {
+				expectedPicOrderCnt += sps.RefFramesInPicOrderCntCycle[i]
+			}
+		}
+		if !isRef {
+			expectedPicOrderCnt += sps.OffsetForNonRefPic
+		}
+		switch {
+		case !sh.FieldPicFlag:
+			topFieldOrderCnt = expectedPicOrderCnt + int(sh.DeltaPicOrderCnt[0])
+			bottomFieldOrderCnt = topFieldOrderCnt + sps.OffsetForTopToBottomField + int(sh.DeltaPicOrderCnt[1])
+		case !sh.BottomFieldFlag:
+			topFieldOrderCnt = expectedPicOrderCnt + int(sh.DeltaPicOrderCnt[0])
+			bottomFieldOrderCnt = topFieldOrderCnt
+		default:
+			bottomFieldOrderCnt = expectedPicOrderCnt + sps.OffsetForTopToBottomField + int(sh.DeltaPicOrderCnt[0])
+			topFieldOrderCnt = bottomFieldOrderCnt
+		}
+		p.updateFrameNum(sh, frameNumOffset)
+	default: // 8.2.1.3, pic_order_cnt_type == 2
+		frameNumOffset := p.frameNumOffset(sh, isIDR, maxFrameNum)
+		var tempPicOrderCnt int
+		switch {
+		case isIDR:
+			tempPicOrderCnt = 0
+		case !isRef:
+			tempPicOrderCnt = 2*(frameNumOffset+int(sh.FrameNum)) - 1
+		default:
+			tempPicOrderCnt = 2 * (frameNumOffset + int(sh.FrameNum))
+		}
+		topFieldOrderCnt, bottomFieldOrderCnt = tempPicOrderCnt, tempPicOrderCnt
+		p.updateFrameNum(sh, frameNumOffset)
+	}
+	return minPOC(sh, topFieldOrderCnt, bottomFieldOrderCnt)
+}
+
+// frameNumOffset - FrameNumOffset for pic_order_cnt_type 1 and 2
+func (p *POCCalculator) frameNumOffset(sh *SliceHeader, isIDR bool, maxFrameNum int) int {
+	switch {
+	case isIDR:
+		return 0
+	case p.prevFrameNum > sh.FrameNum:
+		return p.prevFrameNumOffset + maxFrameNum
+	default:
+		return p.prevFrameNumOffset
+	}
+}
+
+func (p *POCCalculator) updateFrameNum(sh *SliceHeader, frameNumOffset int) {
+	p.prevFrameNum = sh.FrameNum
+	p.prevFrameNumOffset = frameNumOffset
+	if sh.HasMMCO5 {
+		p.prevFrameNum = 0
+		p.prevFrameNumOffset = 0
+	}
+}
+
+func minPOC(sh *SliceHeader, topFieldOrderCnt, bottomFieldOrderCnt int) int {
+	switch {
+	case sh.FieldPicFlag && sh.BottomFieldFlag:
+		return bottomFieldOrderCnt
+	case sh.FieldPicFlag:
+		return topFieldOrderCnt
+	case bottomFieldOrderCnt < topFieldOrderCnt:
+		return bottomFieldOrderCnt
+	default:
+		return topFieldOrderCnt
+	}
+}
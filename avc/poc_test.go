@@ -0,0 +1,115 @@
+package avc
+
+import (
+	"os"
+	"testing"
+)
+
+func TestPOCTwoFrames(t *testing.T) {
+	data, err := os.ReadFile("testdata/two-frames.264")
+	if err != nil {
+		t.Fatal(err)
+	}
+	nalus, err := GetNalusFromSample(data)
+	if err != nil {
+		t.Fatal(err)
+	}
+	spsMap := make(map[uint32]*SPS, 1)
+	ppsMap := make(map[uint32]*PPS, 1)
+	pc := NewPOCCalculator()
+	var pocs []int
+	for _, nalu := range nalus {
+		naluType := GetNaluType(nalu[0])
+		switch naluType {
+		case NALU_SPS:
+			sps, err := ParseSPSNALUnit(nalu, true)
+			if err != nil {
+				t.Fatal(err)
+			}
+			spsMap[sps.ParameterID] = sps
+		case NALU_PPS:
+			pps, err := ParsePPSNALUnit(nalu, spsMap)
+			if err != nil {
+				t.Fatal(err)
+			}
+			ppsMap[pps.PicParameterSetID] = pps
+		case NALU_IDR, NALU_NON_IDR:
+			sh, err := ParseSliceHeader(nalu, spsMap, ppsMap)
+			if err != nil {
+				t.Fatal(err)
+			}
+			poc := pc.Compute(sh, spsMap[0], naluType == NALU_IDR, nalu[0]>>5&0x3)
+			pocs = append(pocs, poc)
+		}
+	}
+	wantedPOCs := []int{0, 2}
+	if len(pocs) != len(wantedPOCs) {
+		t.Fatalf("got %d POCs instead of %d", len(pocs), len(wantedPOCs))
+	}
+	for i := range pocs {
+		if pocs[i] != wantedPOCs[i] {
+			t.Errorf("picture %d: got POC %d instead of %d", i, pocs[i], wantedPOCs[i])
+		}
+	}
+}
+
+func TestPOCCalculator(t *testing.T) {
+	type picture struct {
+		sh        SliceHeader
+		isIDR     bool
+		nalRefIDC byte
+		wantedPOC int
+	}
+	testCases := []struct {
+		desc     string
+		sps      SPS
+		pictures []picture
+	}{
+		{
+			desc: "type 0 with lsb wrap",
+			sps:  SPS{PicOrderCntType: 0, Log2MaxPicOrderCntLsbMinus4: 0},
+			pictures: []picture{
+				{SliceHeader{PicOrderCntLsb: 0}, true, 3, 0},
+				{SliceHeader{PicOrderCntLsb: 6}, false, 2, 6},
+				{SliceHeader{PicOrderCntLsb: 2}, false, 0, 2},
+				{SliceHeader{PicOrderCntLsb: 12}, false, 2, 12},
+				{SliceHeader{PicOrderCntLsb: 2}, false, 2, 18},
+				{SliceHeader{PicOrderCntLsb: 14}, false, 0, 14},
+				{SliceHeader{PicOrderCntLsb: 0}, true, 3, 0},
+			},
+		},
+		{
+			desc: "type 1 with non-reference pictures",
+			sps: SPS{PicOrderCntType: 1, OffsetForNonRefPic: -2, OffsetForTopToBottomField: 1,
+				RefFramesInPicOrderCntCycle: []int{4}},
+			pictures: []picture{
+				{SliceHeader{FrameNum: 0}, true, 3, 0},
+				{SliceHeader{FrameNum: 1}, false, 2, 4},
+				{SliceHeader{FrameNum: 2}, false, 0, 2},
+				{SliceHeader{FrameNum: 2}, false, 2, 8},
+				{SliceHeader{FrameNum: 3}, false, 0, 6},
+			},
+		},
+		{
+			desc: "type 2 with frame_num wrap",
+			sps:  SPS{PicOrderCntType: 2, Log2MaxFrameNumMinus4: 0},
+			pictures: []picture{
+				{SliceHeader{FrameNum: 0}, true, 3, 0},
+				{SliceHeader{FrameNum: 14}, false, 2, 28},
+				{SliceHeader{FrameNum: 15}, false, 2, 30},
+				{SliceHeader{FrameNum: 0}, false, 2, 32},
+				{SliceHeader{FrameNum: 1}, false, 0, 33},
+			},
+		},
+	}
+	for _, tc := range testCases {
+		pc := NewPOCCalculator()
+		for i, pic := range tc.pictures {
+			sh := pic.sh
+			got := pc.Compute(&sh, &tc.sps, pic.isIDR, pic.nalRefIDC)
+			if got != pic.wantedPOC {
+				t.Errorf("%s: picture %d got POC %d instead of %d", tc.desc, i, got, pic.wantedPOC)
+			}
+		}
+	}
+}
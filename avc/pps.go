@@ -76,7 +76,7 @@ func ParsePPSNALUnit(data []byte, spsMap map[uint32]*SPS) (*PPS, error) {
 				pps.RunLengthMinus1 = append(pps.RunLengthMinus1, rl)
 			}
 		case 2:
-			for iGroup := uint(0); iGroup <= pps.NumSliceGroupsMinus1; iGroup++ {
+			for iGroup := uint(0); iGroup < pps.NumSliceGroupsMinus1; iGroup++ {
 				tl := reader.ReadExpGolomb()
 				pps.TopLeft = append(pps.TopLeft, tl)
 				br := reader.ReadExpGolomb()
@@ -86,10 +86,11 @@ func ParsePPSNALUnit(data []byte, spsMap map[uint32]*SPS) (*PPS, error) {
 			pps.SliceGroupChangeDirectionFlag = reader.ReadFlag()
 			pps.SliceGroupChangeRateMinus1 = reader.ReadExpGolomb()
 		case 6:
+			pps.PicSizeInMapUnitsMinus1 = reader.ReadExpGolomb()
 			// slice_group_id[i] has Ceil(Log2(num_slice_groups_minus1 +1) bits)
 			nrBits := bits.CeilLog2(pps.NumSliceGroupsMinus1 + 1)
 
-			for iGroup := uint(0); iGroup <= pps.NumSliceGroupsMinus1; iGroup++ {
+			for i := uint(0); i <= pps.PicSizeInMapUnitsMinus1; i++ {
 				sgi := reader.Read(nrBits)
 				pps.SliceGroupID = append(pps.SliceGroupID, sgi)
 			}
@@ -102,6 +103,7 @@ func ParsePPSNALUnit(data []byte, spsMap map[uint32]*SPS) (*PPS, error) {
 	pps.PicInitQpMinus26 = reader.ReadSignedGolomb()
 	pps.PicInitQsMinus26 = reader.ReadSignedGolomb()
 	pps.ChromaQpIndexOffset = reader.ReadSignedGolomb()
+	pps.SecondChromaQpIndexOffset = pps.ChromaQpIndexOffset // Inferred if not present
 	pps.DeblockingFilterControlPresentFlag = reader.ReadFlag()
 	pps.ConstrainedIntraPredFlag = reader.ReadFlag()
 	pps.RedundantPicCntPresentFlag = reader.ReadFlag()
@@ -132,20 +134,20 @@ func ParsePPSNALUnit(data []byte, spsMap map[uint32]*SPS) (*PPS, error) {
 				} else {
 					nrScalingLists += 6
 				}
-				pps.PicScalingLists = make([]ScalingList, nrScalingLists)
-
-				for i := 0; i < nrScalingLists; i++ {
-					picScalingPresent := reader.ReadFlag()
-					if !picScalingPresent {
-						pps.PicScalingLists[i] = nil
-						continue
-					}
-					sizeOfScalingList := 16 // 4x4 for i < 6
-					if i >= 6 {
-						sizeOfScalingList = 64 // 8x8 for i >= 6
-					}
-					pps.PicScalingLists[i] = readScalingList(reader, sizeOfScalingList)
+			}
+			pps.PicScalingLists = make([]ScalingList, nrScalingLists)
+
+			for i := 0; i < nrScalingLists; i++ {
+				picScalingPresent := reader.ReadFlag()
+				if !picScalingPresent {
+					pps.PicScalingLists[i] = nil
+					continue
+				}
+				sizeOfScalingList := 16 // 4x4 for i < 6
+				if i >= 6 {
+					sizeOfScalingList = 64 // 8x8 for i >= 6
 				}
+				pps.PicScalingLists[i] = readScalingList(reader, sizeOfScalingList)
 			}
 		}
 		pps.SecondChromaQpIndexOffset = reader.ReadSignedGolomb()
@@ -167,3 +169,98 @@ func ParsePPSNALUnit(data []byte, spsMap map[uint32]*SPS) (*PPS, error) {
 	}
 	return pps, nil
 }
+
+// EncodePPSNALUnit - encode PPS as NAL unit with NAL header (nal_ref_idc = 3) and
+// start code emulation prevention bytes.
+// The fields after RedundantPicCntPresentFlag are only written if Transform8x8ModeFlag or
+// PicScalingMatrixPresentFlag is set, or if SecondChromaQpIndexOffset differs from ChromaQpIndexOffset,
+// which is the value inferred when the fields are absent.
+// With Transform8x8ModeFlag, 6 8x8 scaling lists are written (chroma_format_idc == 3)
+// if there are more than 8 PicScalingLists, and otherwise 2.
+func EncodePPSNALUnit(pps *PPS) ([]byte, error) {
+	buf := bytes.Buffer{}
+	w := bits.NewEBSPWriter(&buf)
+	w.Write(0x60|uint(NALU_PPS), 8)
+	w.WriteExpGolomb(uint(pps.PicParameterSetID))
+	w.WriteExpGolomb(uint(pps.SeqParameterSetID))
+	w.WriteFlag(pps.EntropyCodingModeFlag)
+	w.WriteFlag(pps.BottomFieldPicOrderInFramePresentFlag)
+	w.WriteExpGolomb(pps.NumSliceGroupsMinus1)
+
+	if pps.NumSliceGroupsMinus1 > 0 {
+		w.WriteExpGolomb(pps.SliceGroupMapType)
+		switch pps.SliceGroupMapType {
+		case 0:
+			if len(pps.RunLengthMinus1) != int(pps.NumSliceGroupsMinus1+1) {
+				return nil, fmt.Errorf("pps: %d run_length_minus1 for %d slice groups",
+					len(pps.RunLengthMinus1), pps.NumSliceGroupsMinus1+1)
+			}
+			for _, rl := range pps.RunLengthMinus1 {
+				w.WriteExpGolomb(rl)
+			}
+		case 2:
+			if len(pps.TopLeft) != int(pps.NumSliceGroupsMinus1) ||
+				len(pps.BottomRight) != int(pps.NumSliceGroupsMinus1) {
+				return nil, fmt.Errorf("pps: %d top_left and %d bottom_right for %d slice groups",
+					len(pps.TopLeft), len(pps.BottomRight), pps.NumSliceGroupsMinus1+1)
+			}
+			for iGroup := range pps.TopLeft {
+				w.WriteExpGolomb(pps.TopLeft[iGroup])
+				w.WriteExpGolomb(pps.BottomRight[iGroup])
+			}
+		case 3, 4, 5:
+			w.WriteFlag(pps.SliceGroupChangeDirectionFlag)
+			w.WriteExpGolomb(pps.SliceGroupChangeRateMinus1)
+		case 6:
+			if len(pps.SliceGroupID) != int(pps.PicSizeInMapUnitsMinus1+1) {
+				return nil, fmt.Errorf("pps: %d slice_group_id for %d map units",
+					len(pps.SliceGroupID), pps.PicSizeInMapUnitsMinus1+1)
+			}
+			w.WriteExpGolomb(pps.PicSizeInMapUnitsMinus1)
+			nrBits := bits.CeilLog2(pps.NumSliceGroupsMinus1 + 1)
+			for _, sgi := range pps.SliceGroupID {
+				w.Write(sgi, nrBits)
+			}
+		}
+	}
+	w.WriteExpGolomb(pps.NumRefIdxI0DefaultActiveMinus1)
+	w.WriteExpGolomb(pps.NumRefIdxI1DefaultActiveMinus1)
+	w.WriteFlag(pps.WeightedPredFlag)
+	w.Write(pps.WeightedBipredIDC, 2)
+	w.WriteSignedGolomb(pps.PicInitQpMinus26)
+	w.WriteSignedGolomb(pps.PicInitQsMinus26)
+	w.WriteSignedGolomb(pps.ChromaQpIndexOffset)
+	w.WriteFlag(pps.DeblockingFilterControlPresentFlag)
+	w.WriteFlag(pps.ConstrainedIntraPredFlag)
+	w.WriteFlag(pps.RedundantPicCntPresentFlag)
+
+	if pps.Transform8x8ModeFlag || pps.PicScalingMatrixPresentFlag || pps.SecondChromaQpIndexOffset != pps.ChromaQpIndexOffset {
+		w.WriteFlag(pps.Transform8x8ModeFlag)
+		w.WriteFlag(pps.PicScalingMatrixPresentFlag)
+		if pps.PicScalingMatrixPresentFlag {
+			nrScalingLists := 6
+			if pps.Transform8x8ModeFlag {
+				nrScalingLists += 2
+				if len(pps.PicScalingLists) > 8 {
+					nrScalingLists += 4
+				}
+			}
+			for i := 0; i < nrScalingLists; i++ {
+				var scalingList ScalingList
+				if i < len(pps.PicScalingLists) {
+					scalingList = pps.PicScalingLists[i]
+				}
+				w.WriteFlag(scalingList != nil)
+				if scalingList != nil {
+					writeScalingList(w, scalingList)
+				}
+			}
+		}
+		w.WriteSignedGolomb(pps.SecondChromaQpIndexOffset)
+	}
+	w.WriteRbspTrailingBits()
+	if w.AccError() != nil {
+		return nil, w.AccError()
+	}
+	return buf.Bytes(), nil
+}
@@ -1,6 +1,7 @@
 package avc
 
 import (
+	"bytes"
 	"encoding/hex"
 	"testing"
 
@@ -42,3 +43,76 @@ func TestPPSParser(t *testing.T) {
 		t.Error(diff)
 	}
 }
+
+func TestEncodePPSNALUnit(t *testing.T) {
+	byteData, _ := hex.DecodeString(pps1)
+	pps, err := ParsePPSNALUnit(byteData, nil)
+	if err != nil {
+		t.Fatal(err)
+	}
+	encoded, err := EncodePPSNALUnit(pps)
+	if err != nil {
+		t.Fatal(err)
+	}
+	if !bytes.Equal(encoded, byteData) {
+		t.Errorf("encoded PPS %x differs from %s", encoded, pps1)
+	}
+
+	list4x4 := make(ScalingList, 16)
+	list8x8 := make(ScalingList, 64)
+	for i := range list8x8 {
+		if i < 16 {
+			list4x4[i] = 16 + i
+		}
+		list8x8[i] = 200 - 3*i
+	}
+	testCases := []struct {
+		desc            string
+		chromaFormatIDC byte
+		pps             PPS
+	}{
+		{"slice group map type 0", 1, PPS{NumSliceGroupsMinus1: 2, SliceGroupMapType: 0,
+			RunLengthMinus1: []uint{3, 0, 7}}},
+		{"slice group map type 2", 1, PPS{NumSliceGroupsMinus1: 2, SliceGroupMapType: 2,
+			TopLeft: []uint{0, 4}, BottomRight: []uint{10, 20}}},
+		{"slice group map type 4", 1, PPS{NumSliceGroupsMinus1: 1, SliceGroupMapType: 4,
+			SliceGroupChangeDirectionFlag: true, SliceGroupChangeRateMinus1: 5}},
+		{"slice group map type 6", 1, PPS{NumSliceGroupsMinus1: 2, SliceGroupMapType: 6,
+			PicSizeInMapUnitsMinus1: 4, SliceGroupID: []uint{0, 1, 2, 2, 1}}},
+		{"4x4 scaling lists", 1, PPS{PicInitQpMinus26: -5, ChromaQpIndexOffset: 3, PicScalingMatrixPresentFlag: true,
+			PicScalingLists: []ScalingList{list4x4, nil, nil, list4x4, nil, nil}}},
+		{"8x8 scaling lists", 1, PPS{Transform8x8ModeFlag: true, PicScalingMatrixPresentFlag: true,
+			PicScalingLists: []ScalingList{nil, nil, nil, nil, nil, list4x4, list8x8, nil}, SecondChromaQpIndexOffset: -1}},
+		{"inferred second chroma qp index offset", 1, PPS{ChromaQpIndexOffset: -2, SecondChromaQpIndexOffset: -2}},
+		{"zero second chroma qp index offset", 1, PPS{ChromaQpIndexOffset: -2, SecondChromaQpIndexOffset: 0}},
+		{"default scaling lists", 1, PPS{PicScalingMatrixPresentFlag: true,
+			PicScalingLists: []ScalingList{{}, nil, nil, list4x4, nil, {}}}},
+		{"4:4:4 scaling lists", 3, PPS{Transform8x8ModeFlag: true, PicScalingMatrixPresentFlag: true,
+			PicScalingLists: []ScalingList{nil, nil, nil, nil, nil, nil, nil, nil, nil, nil, nil, list8x8}}},
+	}
+	for _, tc := range testCases {
+		spsMap := map[uint32]*SPS{0: {ChromaFormatIDC: tc.chromaFormatIDC}}
+		encoded, err := EncodePPSNALUnit(&tc.pps)
+		if err != nil {
+			t.Errorf("%s: %s", tc.desc, err)
+			continue
+		}
+		got, err := ParsePPSNALUnit(encoded, spsMap)
+		if err != nil {
+			t.Errorf("%s: %s", tc.desc, err)
+			continue
+		}
+		if diff := deep.Equal(*got, tc.pps); diff != nil {
+			t.Errorf("%s: %v", tc.desc, diff)
+		}
+	}
+
+	// With second_chroma_qp_index_offset equal to chroma_qp_index_offset, the trailing fields are inferred
+	encoded, err = EncodePPSNALUnit(&PPS{ChromaQpIndexOffset: -2, SecondChromaQpIndexOffset: -2})
+	if err != nil {
+		t.Fatal(err)
+	}
+	if wanted := "68ce3288"; hex.EncodeToString(encoded) != wanted {
+		t.Errorf("encoded PPS %x, wanted %s", encoded, wanted)
+	}
+}
@@ -123,6 +123,44 @@ type SliceHeader struct {
 	LongTermReferenceFlag         bool
 	SPForSwitchFlag               bool
 	AdaptiveRefPicMarkingModeFlag bool
+	HasMMCO5                      bool // memory_management_control_operation equal to 5 present
+	RefPicListModificationsL0     []RefPicListModification
+	RefPicListModificationsL1     []RefPicListModification
+}
+
+// RefPicListModification - one modification_of_pic_nums_idc entry in ref_pic_list_modification.
+// Value is abs_diff_pic_num_minus1 (idc 0, 1), long_term_pic_num (idc 2),
+// or abs_diff_view_idx_minus1 (idc 4, 5).
+type RefPicListModification struct {
+	ModificationOfPicNumsIDC uint32
+	Value                    uint32
+}
+
+// parseRefPicListModifications parses one list of ref_pic_list_modification entries
+// until modification_of_pic_nums_idc == 3. The last values are also stored in the scalar fields of sh.
+func parseRefPicListModifications(r *bits.EBSPReader, sh *SliceHeader) []RefPicListModification {
+	var mods []RefPicListModification
+	for {
+		sh.ModificationOfPicNumsIDC = uint32(r.ReadExpGolomb())
+		mod := RefPicListModification{ModificationOfPicNumsIDC: sh.ModificationOfPicNumsIDC}
+		switch sh.ModificationOfPicNumsIDC {
+		case 0, 1:
+			sh.AbsDiffPicNumMinus1 = uint32(r.ReadExpGolomb())
+			mod.Value = sh.AbsDiffPicNumMinus1
+		case 2:
+			sh.LongTermPicNum = uint32(r.ReadExpGolomb())
+			mod.Value = sh.LongTermPicNum
+		case 4, 5:
+			sh.AbsDiffViewIdxMinus1 = uint32(r.ReadExpGolomb())
+			mod.Value = sh.AbsDiffViewIdxMinus1
+		case 3:
+			return mods
+		}
+		if r.AccError() != nil {
+			return mods
+		}
+		mods = append(mods, mod)
+	}
 }
 
 // ParseSliceHeader parses AVC slice header following the syntax in ISO/IEC 14496-10 section 7.3.3
@@ -148,10 +186,10 @@ func ParseSliceHeader(nalu []byte, spsMap map[uint32]*SPS, ppsMap map[uint32]*PP
 	if !ok {
 		return nil, fmt.Errorf("pps ID %d unknown", sh.PicParamID)
 	}
-	spsID := pps.PicParameterSetID
-	sps, ok := spsMap[uint32(spsID)]
+	sh.SeqParamID = pps.SeqParameterSetID
+	sps, ok := spsMap[sh.SeqParamID]
 	if !ok {
-		return nil, fmt.Errorf("sps ID %d unknown", spsID)
+		return nil, fmt.Errorf("sps ID %d unknown", sh.SeqParamID)
 	}
 	if sps.SeparateColourPlaneFlag {
 		sh.ColorPlaneID = uint32(r.Read(2))
@@ -205,45 +243,13 @@ func ParseSliceHeader(nalu []byte, spsMap map[uint32]*SPS, ppsMap map[uint32]*PP
 	if sliceType != SLICE_I && sliceType != SLICE_SI {
 		sh.RefPicListModificationL0Flag = r.ReadFlag()
 		if sh.RefPicListModificationL0Flag {
-		refPicListL0Loop:
-			for {
-				sh.ModificationOfPicNumsIDC = uint32(r.ReadExpGolomb())
-				switch sh.ModificationOfPicNumsIDC {
-				case 0, 1:
-					sh.AbsDiffPicNumMinus1 = uint32(r.ReadExpGolomb())
-				case 2:
-					sh.LongTermPicNum = uint32(r.ReadExpGolomb())
-				case 4, 5:
-					sh.AbsDiffViewIdxMinus1 = uint32(r.ReadExpGolomb())
-				case 3:
-					break refPicListL0Loop
-				}
-				if r.AccError() != nil {
-					break refPicListL0Loop
-				}
-			}
+			sh.RefPicListModificationsL0 = parseRefPicListModifications(r, &sh)
 		}
 	}
 	if sliceType == SLICE_B {
 		sh.RefPicListModificationL1Flag = r.ReadFlag()
 		if sh.RefPicListModificationL1Flag {
-		refPicListL1Loop:
-			for {
-				sh.ModificationOfPicNumsIDC = uint32(r.ReadExpGolomb())
-				switch sh.ModificationOfPicNumsIDC {
-				case 0, 1:
-					sh.AbsDiffPicNumMinus1 = uint32(r.ReadExpGolomb())
-				case 2:
-					sh.LongTermPicNum = uint32(r.ReadExpGolomb())
-				case 4, 5:
-					sh.AbsDiffViewIdxMinus1 = uint32(r.ReadExpGolomb())
-				case 3:
-					break refPicListL1Loop
-				}
-				if r.AccError() != nil {
-					break refPicListL1Loop
-				}
-			}
+			sh.RefPicListModificationsL1 = parseRefPicListModifications(r, &sh)
 		}
 	}
 	// end ref_pic_list_modification
@@ -309,6 +315,8 @@ func ParseSliceHeader(nalu []byte, spsMap map[uint32]*SPS, ppsMap map[uint32]*PP
 				for {
 					memoryManagementControlOperation := r.ReadExpGolomb()
 					switch memoryManagementControlOperation {
+					case 5:
+						sh.HasMMCO5 = true
 					case 1, 3:
 						sh.DifferenceOfPicNumsMinus1 = uint32(r.ReadExpGolomb())
 					case 2:
@@ -356,6 +364,10 @@ func ParseSliceHeader(nalu []byte, spsMap map[uint32]*SPS, ppsMap map[uint32]*PP
 		sh.SliceGroupChangeCycle = uint32(r.Read(nrBits))
 	}
 
+	if r.AccError() != nil {
+		return nil, r.AccError()
+	}
+
 	// compute the size in bytes. The last byte may not be fully parsed
 	sh.Size = uint32(r.NrBytesRead())
 	return &sh, nil
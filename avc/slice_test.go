@@ -1,10 +1,12 @@
 package avc
 
 import (
+	"bytes"
 	"encoding/hex"
 	"os"
 	"testing"
 
+	"github.com/Eyevinn/mp4ff/bits"
 	"github.com/go-test/deep"
 )
 
@@ -166,3 +168,71 @@ func TestParseSliceHeaderLength(t *testing.T) {
 		t.Errorf("got %d want %d", sh.Size, wantedSliceHeaderSize)
 	}
 }
+
+func TestParseTruncatedSliceHeader(t *testing.T) {
+	spsHex := "6764001eacd940a02ff9610000030001000003003c8f162d96"
+	ppsHex := "68ebecb22c"
+	naluStartHex := "419a6649e10f2653022fff8700000302c8a32d32"
+	spsData, _ := hex.DecodeString(spsHex)
+	sps, err := ParseSPSNALUnit(spsData, true)
+	if err != nil {
+		t.Error(err)
+	}
+	spsMap := map[uint32]*SPS{sps.ParameterID: sps}
+	ppsData, _ := hex.DecodeString(ppsHex)
+	pps, err := ParsePPSNALUnit(ppsData, spsMap)
+	if err != nil {
+		t.Error(err)
+	}
+	ppsMap := map[uint32]*PPS{pps.PicParameterSetID: pps}
+	naluStart, _ := hex.DecodeString(naluStartHex)
+	for _, length := range []int{2, 4, 8, 10} {
+		_, err := ParseSliceHeader(naluStart[:length], spsMap, ppsMap)
+		if err == nil {
+			t.Errorf("expected error for slice header truncated to %d bytes", length)
+		}
+	}
+}
+
+func TestParseRefPicListModifications(t *testing.T) {
+	sps := &SPS{FrameMbsOnlyFlag: true, PicOrderCntType: 2}
+	pps := &PPS{}
+	spsMap := map[uint32]*SPS{0: sps}
+	ppsMap := map[uint32]*PPS{0: pps}
+
+	buf := bytes.Buffer{}
+	w := bits.NewEBSPWriter(&buf)
+	w.Write(0x41, 8)        // nal_ref_idc 2, non-IDR slice
+	w.WriteExpGolomb(0)     // first_mb_in_slice
+	w.WriteExpGolomb(0)     // slice_type P
+	w.WriteExpGolomb(0)     // pic_parameter_set_id
+	w.Write(1, 4)           // frame_num
+	w.WriteFlag(false)      // num_ref_idx_active_override_flag
+	w.WriteFlag(true)       // ref_pic_list_modification_flag_l0
+	w.WriteExpGolomb(0)     // modification_of_pic_nums_idc
+	w.WriteExpGolomb(2)     // abs_diff_pic_num_minus1
+	w.WriteExpGolomb(2)     // modification_of_pic_nums_idc
+	w.WriteExpGolomb(5)     // long_term_pic_num
+	w.WriteExpGolomb(3)     // modification_of_pic_nums_idc (end)
+	w.WriteFlag(false)      // adaptive_ref_pic_marking_mode_flag
+	w.WriteSignedGolomb(-1) // slice_qp_delta
+	w.WriteRbspTrailingBits()
+
+	sh, err := ParseSliceHeader(buf.Bytes(), spsMap, ppsMap)
+	if err != nil {
+		t.Fatal(err)
+	}
+	wantedMods := []RefPicListModification{
+		{ModificationOfPicNumsIDC: 0, Value: 2},
+		{ModificationOfPicNumsIDC: 2, Value: 5},
+	}
+	if diff := deep.Equal(sh.RefPicListModificationsL0, wantedMods); diff != nil {
+		t.Error(diff)
+	}
+	if sh.RefPicListModificationsL1 != nil {
+		t.Errorf("got L1 modifications %v", sh.RefPicListModificationsL1)
+	}
+	if sh.SliceQPDelta != -1 {
+		t.Errorf("got slice_qp_delta %d instead of -1", sh.SliceQPDelta)
+	}
+}
@@ -13,9 +13,16 @@ const ExtendedSAR = 255
 
 // SPS errors
 var (
-	ErrNotSPS = errors.New("not an SPS NAL unit")
+	ErrNotSPS                 = errors.New("not an SPS NAL unit")
+	ErrInvalidChromaFormatIDC = errors.New("invalid chroma_format_idc")
+
+	// ErrInvalidNumRefFramesInPicOrderCntCycle - num_ref_frames_in_pic_order_cnt_cycle larger than 255
+	ErrInvalidNumRefFramesInPicOrderCntCycle = errors.New("invalid num_ref_frames_in_pic_order_cnt_cycle")
 )
 
+// maxNumRefFramesInPicOrderCntCycle - upper limit for num_ref_frames_in_pic_order_cnt_cycle (Section 7.4.2.1.1)
+const maxNumRefFramesInPicOrderCntCycle = 255
+
 // SPS - AVC SPS parameters
 type SPS struct {
 	Profile                         uint32
@@ -33,9 +40,9 @@ type SPS struct {
 	PicOrderCntType                 uint
 	Log2MaxPicOrderCntLsbMinus4     uint
 	DeltaPicOrderAlwaysZeroFlag     bool
-	OffsetForNonRefPic              uint
-	OffsetForTopToBottomField       uint
-	RefFramesInPicOrderCntCycle     []uint
+	OffsetForNonRefPic              int
+	OffsetForTopToBottomField       int
+	RefFramesInPicOrderCntCycle     []int
 	NumRefFrames                    uint
 	GapsInFrameNumValueAllowedFlag  bool
 	FrameMbsOnlyFlag                bool
@@ -53,11 +60,18 @@ type SPS struct {
 	VUI                             *VUIParameters
 }
 
-// ScalingList - 4x4 or 8x8 Scaling lists. Nil if not present
+// ScalingList - 4x4 or 8x8 Scaling lists. Nil if not present.
+// An empty non-nil list signals useDefaultScalingMatrixFlag, so that Default_4x4 or Default_8x8 is used.
 type ScalingList []int
 
+// UseDefaultMatrix - true if the list signals that the default scaling matrix should be used
+func (s ScalingList) UseDefaultMatrix() bool {
+	return s != nil && len(s) == 0
+}
+
 // VUIParameters - extra parameters according to 14496-10, E.1
 type VUIParameters struct {
+	AspectRatioIDC                     uint // 0 if aspect_ratio_info_present_flag is not set
 	SampleAspectRatioWidth             uint
 	SampleAspectRatioHeight            uint
 	OverscanInfoPresentFlag            bool
@@ -113,6 +127,16 @@ type CpbEntry struct {
 
 // ParseSPSNALUnit - Parse AVC SPS NAL unit starting with NAL header
 func ParseSPSNALUnit(data []byte, parseVUIBeyondAspectRatio bool) (*SPS, error) {
+	return parseSPSNALUnit(data, parseVUIBeyondAspectRatio, false)
+}
+
+// ParseSPSNALUnitStrict - Parse AVC SPS NAL unit including full VUI and check that
+// the NAL unit ends with correct rbsp_trailing_bits.
+func ParseSPSNALUnitStrict(data []byte) (*SPS, error) {
+	return parseSPSNALUnit(data, true, true)
+}
+
+func parseSPSNALUnit(data []byte, parseVUIBeyondAspectRatio, checkTrailingBits bool) (*SPS, error) {
 
 	sps := &SPS{}
 
@@ -140,6 +164,9 @@ func ParseSPSNALUnit(data []byte, parseVUIBeyondAspectRatio bool) (*SPS, error)
 	switch sps.Profile {
 	case 100, 110, 122, 244, 44, 83, 86, 118, 128, 138, 139, 134, 135:
 		sps.ChromaFormatIDC = byte(reader.ReadExpGolomb())
+		if sps.ChromaFormatIDC > 3 && reader.AccError() == nil {
+			return nil, fmt.Errorf("sps: %w %d", ErrInvalidChromaFormatIDC, sps.ChromaFormatIDC)
+		}
 		if sps.ChromaFormatIDC == 3 {
 			sps.SeparateColourPlaneFlag = reader.ReadFlag()
 		}
@@ -178,12 +205,15 @@ func ParseSPSNALUnit(data []byte, parseVUIBeyondAspectRatio bool) (*SPS, error)
 		sps.Log2MaxPicOrderCntLsbMinus4 = reader.ReadExpGolomb()
 	case 1:
 		sps.DeltaPicOrderAlwaysZeroFlag = reader.ReadFlag()
-		sps.OffsetForNonRefPic = reader.ReadExpGolomb()
-		sps.OffsetForTopToBottomField = reader.ReadExpGolomb()
+		sps.OffsetForNonRefPic = reader.ReadSignedGolomb()
+		sps.OffsetForTopToBottomField = reader.ReadSignedGolomb()
 		numRefFramesInPicOrderCntCycle := reader.ReadExpGolomb()
-		sps.RefFramesInPicOrderCntCycle = make([]uint, numRefFramesInPicOrderCntCycle)
+		if numRefFramesInPicOrderCntCycle > maxNumRefFramesInPicOrderCntCycle && reader.AccError() == nil {
+			return nil, fmt.Errorf("sps: %w %d", ErrInvalidNumRefFramesInPicOrderCntCycle, numRefFramesInPicOrderCntCycle)
+		}
+		sps.RefFramesInPicOrderCntCycle = make([]int, numRefFramesInPicOrderCntCycle)
 		for i := 0; i < int(numRefFramesInPicOrderCntCycle); i++ {
-			sps.RefFramesInPicOrderCntCycle[i] = reader.ReadExpGolomb()
+			sps.RefFramesInPicOrderCntCycle[i] = reader.ReadSignedGolomb()
 		}
 	}
 
@@ -202,25 +232,13 @@ func ParseSPSNALUnit(data []byte, parseVUIBeyondAspectRatio bool) (*SPS, error)
 	}
 	sps.Direct8x8InferenceFlag = reader.ReadFlag()
 	sps.FrameCroppingFlag = reader.ReadFlag()
-	var cropUnitX, cropUnitY uint
-	var frameMbsOnly uint = 0
-	if sps.FrameMbsOnlyFlag {
-		frameMbsOnly = 1
-	} else { // Interlaced so the height should be doubled
+	if !sps.FrameMbsOnlyFlag { // Interlaced so the height should be doubled
 		sps.Height *= 2
 	}
 	if sps.FrameCroppingFlag {
-		switch sps.ChromaFormatIDC {
-		case 0:
-			cropUnitX, cropUnitY = 1, 2-frameMbsOnly
-		case 1:
-			cropUnitX, cropUnitY = 2, 2*(2-frameMbsOnly)
-		case 2:
-			cropUnitX, cropUnitY = 2, 1*(2-frameMbsOnly)
-		case 3: //This lacks one extra check?
-			cropUnitX, cropUnitY = 1, 1*(2-frameMbsOnly)
-		default:
-			return nil, fmt.Errorf("non-vaild chroma_format_idc value: %d", sps.ChromaFormatIDC)
+		cropUnitX, cropUnitY, err := sps.cropUnits()
+		if err != nil {
+			return nil, err
 		}
 
 		sps.FrameCropLeftOffset = reader.ReadExpGolomb()
@@ -236,13 +254,50 @@ func ParseSPSNALUnit(data []byte, parseVUIBeyondAspectRatio bool) (*SPS, error)
 	}
 
 	vuiParametersPresentFlag := reader.ReadFlag()
+	if reader.AccError() != nil {
+		return sps, fmt.Errorf("sps: %w", reader.AccError())
+	}
 	sps.NrBytesBeforeVUI = reader.NrBytesRead()
 	if vuiParametersPresentFlag {
 		sps.VUI = parseVUI(reader, parseVUIBeyondAspectRatio)
+		if reader.AccError() != nil {
+			return sps, fmt.Errorf("sps vui: %w", reader.AccError())
+		}
 	}
 	sps.NrBytesRead = reader.NrBytesRead()
 
-	return sps, reader.AccError()
+	if checkTrailingBits {
+		err := reader.ReadRbspTrailingBits()
+		if err != nil {
+			return nil, fmt.Errorf("sps: %w", err)
+		}
+	}
+
+	return sps, nil
+}
+
+// cropUnits returns the horizontal and vertical units for the frame crop offsets.
+// CropUnitX and CropUnitY are defined by Equations 7-19 to 7-22 in ISO/IEC 14496-10.
+func (s *SPS) cropUnits() (cropUnitX, cropUnitY uint, err error) {
+	var frameMbsOnly uint = 0
+	if s.FrameMbsOnlyFlag {
+		frameMbsOnly = 1
+	}
+	chromaArrayType := s.ChromaArrayType()
+	// SubWidthC and SubHeightC are 2 and 2 for 4:2:0, 2 and 1 for 4:2:2, and 1 and 1 for 4:4:4
+	switch chromaArrayType {
+	case 0:
+		cropUnitX, cropUnitY = 1, 2-frameMbsOnly
+	case 1:
+		cropUnitX, cropUnitY = 2, 2*(2-frameMbsOnly)
+	case 2:
+		cropUnitX, cropUnitY = 2, 1*(2-frameMbsOnly)
+	case 3:
+		cropUnitX, cropUnitY = 1, 1*(2-frameMbsOnly)
+	default:
+		return 0, 0, fmt.Errorf("%w %d", ErrInvalidChromaFormatIDC, s.ChromaFormatIDC)
+	}
+	return cropUnitX, cropUnitY, nil
 }
 
 // CpbDbpDelaysPresent signals if Cpb and Dbp can be found in Picture Timing SEI
@@ -262,6 +317,40 @@ func (s *SPS) PicStructPresent() bool {
 	return s.VUI.PicStructPresentFlag
 }
 
+// FrameRate returns time_scale / (2 * num_units_in_tick) from the VUI timing info.
+// If FixedFrameRateFlag is not set, this is the maximum frame rate.
+// ok is false if there is no timing info.
+func (s *SPS) FrameRate() (fps float64, ok bool) {
+	if s.VUI == nil {
+		return 0, false
+	}
+	return s.VUI.FrameRate()
+}
+
+// DisplayAspectRatio returns the display aspect ratio as a reduced fraction,
+// given by the cropped picture size and the sample aspect ratio.
+// An unspecified sample aspect ratio (0:0) is treated as 1:1.
+// 0:0 is returned if Width or Height is zero.
+func (s *SPS) DisplayAspectRatio() (darWidth, darHeight uint) {
+	sarWidth, sarHeight := uint(1), uint(1)
+	if s.VUI != nil && s.VUI.SampleAspectRatioWidth != 0 && s.VUI.SampleAspectRatioHeight != 0 {
+		sarWidth, sarHeight = s.VUI.SampleAspectRatioWidth, s.VUI.SampleAspectRatioHeight
+	}
+	darWidth, darHeight = s.Width*sarWidth, s.Height*sarHeight
+	if darWidth == 0 || darHeight == 0 {
+		return 0, 0
+	}
+	g := gcd(darWidth, darHeight)
+	return darWidth / g, darHeight / g
+}
+
+func gcd(a, b uint) uint {
+	for b != 0 {
+		a, b = b, a%b
+	}
+	return a
+}
+
 // ChromaArrayType as defined in Section 7.4.2.1.1 under separate_colour_plane_flag
 func (s *SPS) ChromaArrayType() byte {
 	if !s.SeparateColourPlaneFlag {
@@ -270,6 +359,14 @@ func (s *SPS) ChromaArrayType() byte {
 	return 0
 }
 
+// FrameRate returns time_scale / (2 * num_units_in_tick) if timing info is present.
+func (v *VUIParameters) FrameRate() (fps float64, ok bool) {
+	if !v.TimingInfoPresentFlag || v.NumUnitsInTick == 0 {
+		return 0, false
+	}
+	return float64(v.TimeScale) / float64(2*v.NumUnitsInTick), true
+}
+
 // parseVUI - parse VUI (Visual Usability Information)
 // if parseVUIBeyondAspectRatio is false, stop after AspectRatio has been parsed
 func parseVUI(reader *bits.EBSPReader, parseVUIBeyondAspectRatio bool) *VUIParameters {
@@ -278,6 +375,7 @@ func parseVUI(reader *bits.EBSPReader, parseVUIBeyondAspectRatio bool) *VUIParam
 	aspectRatioInfoPresentFlag := reader.ReadFlag()
 	if aspectRatioInfoPresentFlag {
 		aspectRatioIDC := reader.Read(8)
+		vui.AspectRatioIDC = aspectRatioIDC
 		if aspectRatioIDC == ExtendedSAR {
 			vui.SampleAspectRatioWidth = reader.Read(16)
 			vui.SampleAspectRatioHeight = reader.Read(16)
@@ -363,11 +461,100 @@ func parseHrdParameters(r *bits.EBSPReader) *HrdParameters {
 	return hp
 }
 
+// BitRate returns the bit rate in bits/s of the CPB entry given bit_rate_scale (Eq. E-37)
+func (c *CpbEntry) BitRate(bitRateScale uint) uint64 {
+	return uint64(c.BitRateValueMinus1+1) << (6 + bitRateScale)
+}
+
+// CpbSize returns the CPB size in bits of the CPB entry given cpb_size_scale (Eq. E-38)
+func (c *CpbEntry) CpbSize(cpbSizeScale uint) uint64 {
+	return uint64(c.CpbSizeValueMinus1+1) << (4 + cpbSizeScale)
+}
+
+// MaxBitRate returns the highest bit rate in bits/s of the CPB entries
+func (h *HrdParameters) MaxBitRate() uint64 {
+	var maxBitRate uint64
+	for i := range h.CpbEntries {
+		if bitRate := h.CpbEntries[i].BitRate(h.BitRateScale); bitRate > maxBitRate {
+			maxBitRate = bitRate
+		}
+	}
+	return maxBitRate
+}
+
+// MaxCpbSize returns the largest CPB size in bits of the CPB entries
+func (h *HrdParameters) MaxCpbSize() uint64 {
+	var maxCpbSize uint64
+	for i := range h.CpbEntries {
+		if cpbSize := h.CpbEntries[i].CpbSize(h.CpbSizeScale); cpbSize > maxCpbSize {
+			maxCpbSize = cpbSize
+		}
+	}
+	return maxCpbSize
+}
+
 // ConstraintFlags - return the four ConstraintFlag bits
 func (a *SPS) ConstraintFlags() byte {
 	return byte(a.ProfileCompatibility >> 4)
 }
 
+// ProfileName - human-readable name of the profile, "Unknown" if not known
+func (a *SPS) ProfileName() string {
+	switch a.Profile {
+	case 66:
+		if a.ProfileCompatibility&0x40 != 0 { // constraint_set1_flag
+			return "Constrained Baseline"
+		}
+		return "Baseline"
+	case 77:
+		return "Main"
+	case 88:
+		return "Extended"
+	case 100:
+		return "High"
+	case 110:
+		return "High 10"
+	case 122:
+		return "High 4:2:2"
+	case 244:
+		return "High 4:4:4"
+	case 44:
+		return "CAVLC 4:4:4 Intra"
+	case 83:
+		return "Scalable Baseline"
+	case 86:
+		return "Scalable High"
+	case 118:
+		return "Multiview High"
+	case 128:
+		return "Stereo High"
+	case 134:
+		return "MFC High"
+	case 135:
+		return "MFC Depth High"
+	case 138:
+		return "Multiview Depth High"
+	case 139:
+		return "Enhanced Multiview Depth High"
+	default:
+		return "Unknown"
+	}
+}
+
+// LevelString - level as a string like "3.1", or "1b"
+func (a *SPS) LevelString() string {
+	switch {
+	case a.Level == 9:
+		return "1b"
+	case a.Level == 11 && a.ProfileCompatibility&0x10 != 0: // constraint_set3_flag
+		switch a.Profile {
+		case 66, 77, 88:
+			return "1b"
+		}
+	}
+	return fmt.Sprintf("%d.%d", a.Level/10, a.Level%10)
+}
+
 // GetSARfromIDC - get Sample Aspect Ratio from IDC index
 func GetSARfromIDC(index uint) (uint, uint, error) {
 	if index < 1 || index > 16 {
@@ -389,6 +576,9 @@ func readScalingList(reader *bits.EBSPReader, sizeOfScalingList int) ScalingList
 		if nextScale != 0 {
 			deltaScale := reader.ReadSignedGolomb()
 			nextScale = (lastScale + deltaScale + 256) % 256
+			if j == 0 && nextScale == 0 { // useDefaultScalingMatrixFlag
+				return ScalingList{}
+			}
 		}
 		if nextScale == 0 {
 			scalingList[j] = lastScale
@@ -399,3 +589,222 @@ func readScalingList(reader *bits.EBSPReader, sizeOfScalingList int) ScalingList
 	}
 	return scalingList
 }
+
+// EncodeSPSNALUnit - encode SPS as NAL unit with NAL header (nal_ref_idc = 3) and
+// start code emulation prevention bytes.
+// All VUI fields are written, so the SPS should have been parsed including full VUI.
+// AspectRatioIDC is only kept if it is ExtendedSAR or matches the sample aspect ratio. Otherwise it is
+// derived from the sample aspect ratio, so that the SAR can be changed. 0:0 means no aspect ratio info.
+func EncodeSPSNALUnit(sps *SPS) ([]byte, error) {
+	buf := bytes.Buffer{}
+	w := bits.NewEBSPWriter(&buf)
+	w.Write(0x60|uint(NALU_SPS), 8)
+	w.Write(uint(sps.Profile), 8)
+	w.Write(uint(sps.ProfileCompatibility), 8)
+	w.Write(uint(sps.Level), 8)
+	w.WriteExpGolomb(uint(sps.ParameterID))
+
+	switch sps.Profile {
+	case 100, 110, 122, 244, 44, 83, 86, 118, 128, 138, 139, 134, 135:
+		w.WriteExpGolomb(uint(sps.ChromaFormatIDC))
+		if sps.ChromaFormatIDC == 3 {
+			w.WriteFlag(sps.SeparateColourPlaneFlag)
+		}
+		w.WriteExpGolomb(sps.BitDepthLumaMinus8)
+		w.WriteExpGolomb(sps.BitDepthChromaMinus8)
+		w.WriteFlag(sps.QPPrimeYZeroTransformBypassFlag)
+		w.WriteFlag(sps.SeqScalingMatrixPresentFlag)
+		if sps.SeqScalingMatrixPresentFlag {
+			nrScalingLists := 12
+			if sps.ChromaFormatIDC != 3 {
+				nrScalingLists = 8
+			}
+			for i := 0; i < nrScalingLists; i++ {
+				var scalingList ScalingList
+				if i < len(sps.SeqScalingLists) {
+					scalingList = sps.SeqScalingLists[i]
+				}
+				w.WriteFlag(scalingList != nil)
+				if scalingList != nil {
+					writeScalingList(w, scalingList)
+				}
+			}
+		}
+	}
+
+	w.WriteExpGolomb(sps.Log2MaxFrameNumMinus4)
+	w.WriteExpGolomb(sps.PicOrderCntType)
+	switch sps.PicOrderCntType {
+	case 0:
+		w.WriteExpGolomb(sps.Log2MaxPicOrderCntLsbMinus4)
+	case 1:
+		w.WriteFlag(sps.DeltaPicOrderAlwaysZeroFlag)
+		w.WriteSignedGolomb(sps.OffsetForNonRefPic)
+		w.WriteSignedGolomb(sps.OffsetForTopToBottomField)
+		w.WriteExpGolomb(uint(len(sps.RefFramesInPicOrderCntCycle)))
+		for _, offsetForRefFrame := range sps.RefFramesInPicOrderCntCycle {
+			w.WriteSignedGolomb(offsetForRefFrame)
+		}
+	}
+
+	w.WriteExpGolomb(sps.NumRefFrames)
+	w.WriteFlag(sps.GapsInFrameNumValueAllowedFlag)
+
+	// Width and Height are after cropping, so go back to the size in macroblocks
+	width, height := sps.Width, sps.Height
+	if sps.FrameCroppingFlag {
+		cropUnitX, cropUnitY, err := sps.cropUnits()
+		if err != nil {
+			return nil, err
+		}
+		width += (sps.FrameCropLeftOffset + sps.FrameCropRightOffset) * cropUnitX
+		height += (sps.FrameCropTopOffset + sps.FrameCropBottomOffset) * cropUnitY
+	}
+	if !sps.FrameMbsOnlyFlag {
+		height /= 2
+	}
+	if width < 16 || height < 16 {
+		return nil, fmt.Errorf("sps: too small picture %dx%d", sps.Width, sps.Height)
+	}
+	w.WriteExpGolomb(width/16 - 1)
+	w.WriteExpGolomb(height/16 - 1)
+
+	w.WriteFlag(sps.FrameMbsOnlyFlag)
+	if !sps.FrameMbsOnlyFlag {
+		w.WriteFlag(sps.MbAdaptiveFrameFieldFlag)
+	}
+	w.WriteFlag(sps.Direct8x8InferenceFlag)
+	w.WriteFlag(sps.FrameCroppingFlag)
+	if sps.FrameCroppingFlag {
+		w.WriteExpGolomb(sps.FrameCropLeftOffset)
+		w.WriteExpGolomb(sps.FrameCropRightOffset)
+		w.WriteExpGolomb(sps.FrameCropTopOffset)
+		w.WriteExpGolomb(sps.FrameCropBottomOffset)
+	}
+	w.WriteFlag(sps.VUI != nil)
+	if sps.VUI != nil {
+		writeVUI(w, sps.VUI)
+	}
+	w.WriteRbspTrailingBits()
+	if w.AccError() != nil {
+		return nil, w.AccError()
+	}
+	return buf.Bytes(), nil
+}
+
+func writeVUI(w *bits.EBSPWriter, vui *VUIParameters) {
+	aspectRatioInfoPresentFlag := vui.SampleAspectRatioWidth != 0 || vui.SampleAspectRatioHeight != 0
+	w.WriteFlag(aspectRatioInfoPresentFlag)
+	if aspectRatioInfoPresentFlag {
+		aspectRatioIDC := vui.AspectRatioIDC
+		if aspectRatioIDC != ExtendedSAR {
+			sarWidth, sarHeight, err := GetSARfromIDC(aspectRatioIDC)
+			if err != nil || sarWidth != vui.SampleAspectRatioWidth || sarHeight != vui.SampleAspectRatioHeight {
+				aspectRatioIDC = getIDCfromSAR(vui.SampleAspectRatioWidth, vui.SampleAspectRatioHeight)
+			}
+		}
+		w.Write(aspectRatioIDC, 8)
+		if aspectRatioIDC == ExtendedSAR {
+			w.Write(vui.SampleAspectRatioWidth, 16)
+			w.Write(vui.SampleAspectRatioHeight, 16)
+		}
+	}
+	w.WriteFlag(vui.OverscanInfoPresentFlag)
+	if vui.OverscanInfoPresentFlag {
+		w.WriteFlag(vui.OverscanAppropriateFlag)
+	}
+	w.WriteFlag(vui.VideoSignalTypePresentFlag)
+	if vui.VideoSignalTypePresentFlag {
+		w.Write(vui.VideoFormat, 3)
+		w.WriteFlag(vui.VideoFullRangeFlag)
+		w.WriteFlag(vui.ColourDescriptionFlag)
+		if vui.ColourDescriptionFlag {
+			w.Write(vui.ColourPrimaries, 8)
+			w.Write(vui.TransferCharacteristics, 8)
+			w.Write(vui.MatrixCoefficients, 8)
+		}
+	}
+	w.WriteFlag(vui.ChromaLocInfoPresentFlag)
+	if vui.ChromaLocInfoPresentFlag {
+		w.WriteExpGolomb(vui.ChromaSampleLocTypeTopField)
+		w.WriteExpGolomb(vui.ChromaSampleLocTypeBottomField)
+	}
+	w.WriteFlag(vui.TimingInfoPresentFlag)
+	if vui.TimingInfoPresentFlag {
+		w.Write(vui.NumUnitsInTick, 32)
+		w.Write(vui.TimeScale, 32)
+		w.WriteFlag(vui.FixedFrameRateFlag)
+	}
+	nalHrdPresent := vui.NalHrdParametersPresentFlag && vui.NalHrdParameters != nil
+	w.WriteFlag(nalHrdPresent)
+	if nalHrdPresent {
+		writeHrdParameters(w, vui.NalHrdParameters)
+	}
+	vclHrdPresent := vui.VclHrdParametersPresentFlag && vui.VclHrdParameters != nil
+	w.WriteFlag(vclHrdPresent)
+	if vclHrdPresent {
+		writeHrdParameters(w, vui.VclHrdParameters)
+	}
+	if nalHrdPresent || vclHrdPresent {
+		w.WriteFlag(vui.LowDelayHrdFlag)
+	}
+	w.WriteFlag(vui.PicStructPresentFlag)
+	w.WriteFlag(vui.BitstreamRestrictionFlag)
+	if vui.BitstreamRestrictionFlag {
+		w.WriteFlag(vui.MotionVectorsOverPicBoundariesFlag)
+		w.WriteExpGolomb(vui.MaxBytesPerPicDenom)
+		w.WriteExpGolomb(vui.MaxBitsPerMbDenom)
+		w.WriteExpGolomb(vui.Log2MaxMvLengthHorizontal)
+		w.WriteExpGolomb(vui.Log2MaxMvLengthVertical)
+		w.WriteExpGolomb(vui.MaxNumReorderFrames)
+		w.WriteExpGolomb(vui.MaxDecFrameBuffering)
+	}
+}
+
+func writeHrdParameters(w *bits.EBSPWriter, hp *HrdParameters) {
+	w.WriteExpGolomb(hp.CpbCountMinus1)
+	w.Write(hp.BitRateScale, 4)
+	w.Write(hp.CpbSizeScale, 4)
+	for schedSelIdx := uint(0); schedSelIdx <= hp.CpbCountMinus1; schedSelIdx++ {
+		var ce CpbEntry
+		if int(schedSelIdx) < len(hp.CpbEntries) {
+			ce = hp.CpbEntries[schedSelIdx]
+		}
+		w.WriteExpGolomb(ce.BitRateValueMinus1)
+		w.WriteExpGolomb(ce.CpbSizeValueMinus1)
+		w.WriteFlag(ce.CbrFlag)
+	}
+	w.Write(hp.InitialCpbRemovalDelayLengthMinus1, 5)
+	w.Write(hp.CpbRemovalDelayLengthMinus1, 5)
+	w.Write(hp.DpbOutputDelayLengthMinus1, 5)
+	w.Write(hp.TimeOffsetLength, 5)
+}
+
+// getIDCfromSAR - get aspect_ratio_idc for a Sample Aspect Ratio. ExtendedSAR if not in table
+func getIDCfromSAR(sarWidth, sarHeight uint) uint {
+	for idc := uint(1); idc <= 16; idc++ {
+		w, h, _ := GetSARfromIDC(idc)
+		if w == sarWidth && h == sarHeight {
+			return idc
+		}
+	}
+	return ExtendedSAR
+}
+
+// writeScalingList writes the delta_scale values that reproduce scalingList.
+// For an empty list, delta_scale -8 signals useDefaultScalingMatrixFlag.
+func writeScalingList(w *bits.EBSPWriter, scalingList ScalingList) {
+	if scalingList.UseDefaultMatrix() {
+		w.WriteSignedGolomb(-8)
+		return
+	}
+	lastScale := 8
+	for _, scale := range scalingList {
+		deltaScale := (scale - lastScale + 256) % 256
+		if deltaScale > 127 {
+			deltaScale -= 256
+		}
+		w.WriteSignedGolomb(deltaScale)
+		lastScale = scale
+	}
+}
@@ -1,7 +1,11 @@
 package avc
 
 import (
+	"bytes"
 	"encoding/hex"
+	"errors"
+	"io"
+	"strings"
 	"testing"
 
 	"github.com/go-test/deep"
@@ -46,6 +50,7 @@ func TestSPSParser1(t *testing.T) {
 		Width:                           1280,
 		Height:                          720,
 		VUI: &VUIParameters{
+			AspectRatioIDC:              1,
 			SampleAspectRatioWidth:      1,
 			SampleAspectRatioHeight:     1,
 			VideoSignalTypePresentFlag:  true,
@@ -123,6 +128,7 @@ func TestSPSParser2(t *testing.T) {
 		Width:                           320,
 		Height:                          180,
 		VUI: &VUIParameters{
+			AspectRatioIDC:                     0,
 			SampleAspectRatioWidth:             0,
 			SampleAspectRatioHeight:            0,
 			TimingInfoPresentFlag:              true,
@@ -180,6 +186,7 @@ func TestSPSParser3(t *testing.T) {
 		Width:                           1280,
 		Height:                          720,
 		VUI: &VUIParameters{
+			AspectRatioIDC:              1,
 			SampleAspectRatioWidth:      1,
 			SampleAspectRatioHeight:     1,
 			TimingInfoPresentFlag:       true,
@@ -230,3 +237,399 @@ func TestCodecString(t *testing.T) {
 		t.Errorf("expected codec: %q, got %q", expected, codec)
 	}
 }
+
+func TestSPSParserStrict(t *testing.T) {
+	for _, spsHex := range []string{sps1nalu, sps2nalu, sps3nalu} {
+		byteData, _ := hex.DecodeString(spsHex)
+		_, err := ParseSPSNALUnitStrict(byteData)
+		if err != nil {
+			t.Errorf("strict parsing of %s: %s", spsHex, err)
+		}
+	}
+	byteData, _ := hex.DecodeString(sps1nalu)
+	byteData[len(byteData)-1] = 0x81 // Extra 1 after the stop bit
+	_, err := ParseSPSNALUnit(byteData, true)
+	if err != nil {
+		t.Errorf("lenient parsing of corrupt trailing bits: %s", err)
+	}
+	_, err = ParseSPSNALUnitStrict(byteData)
+	if err == nil {
+		t.Error("expected error for corrupt trailing bits")
+	}
+}
+
+func TestEncodeSPSNALUnit(t *testing.T) {
+	for _, spsHex := range []string{sps1nalu, sps2nalu, sps3nalu} {
+		byteData, _ := hex.DecodeString(spsHex)
+		sps, err := ParseSPSNALUnit(byteData, true)
+		if err != nil {
+			t.Error(err)
+			continue
+		}
+		encoded, err := EncodeSPSNALUnit(sps)
+		if err != nil {
+			t.Error(err)
+			continue
+		}
+		// The NAL header is always written with nal_ref_idc = 3
+		if !bytes.Equal(encoded[1:], byteData[1:]) {
+			t.Errorf("encoded SPS %x differs from %s", encoded, spsHex)
+		}
+		gotSPS, err := ParseSPSNALUnit(encoded, true)
+		if err != nil {
+			t.Error(err)
+			continue
+		}
+		if diff := deep.Equal(gotSPS, sps); diff != nil {
+			t.Error(diff)
+		}
+	}
+}
+
+func TestSPSDefaultScalingMatrix(t *testing.T) {
+	byteData, _ := hex.DecodeString(sps1nalu)
+	sps, err := ParseSPSNALUnit(byteData, true)
+	if err != nil {
+		t.Fatal(err)
+	}
+	flat := make(ScalingList, 16)
+	for i := range flat {
+		flat[i] = 16
+	}
+	sps.SeqScalingMatrixPresentFlag = true
+	sps.SeqScalingLists = []ScalingList{{}, flat, nil, nil, nil, nil, {}, nil}
+	data, err := EncodeSPSNALUnit(sps)
+	if err != nil {
+		t.Fatal(err)
+	}
+	gotSPS, err := ParseSPSNALUnit(data, true)
+	if err != nil {
+		t.Fatal(err)
+	}
+	if diff := deep.Equal(gotSPS, sps); diff != nil {
+		t.Error(diff)
+	}
+	if !gotSPS.SeqScalingLists[0].UseDefaultMatrix() || gotSPS.SeqScalingLists[1].UseDefaultMatrix() {
+		t.Error("use of default scaling matrix not kept")
+	}
+	reEncoded, err := EncodeSPSNALUnit(gotSPS)
+	if err != nil {
+		t.Fatal(err)
+	}
+	if !bytes.Equal(reEncoded, data) {
+		t.Errorf("re-encoded SPS %x differs from %x", reEncoded, data)
+	}
+}
+
+func TestSPSChangedSampleAspectRatio(t *testing.T) {
+	byteData, _ := hex.DecodeString(sps1nalu)
+	testCases := []struct {
+		sarWidth, sarHeight uint
+		wantedIDC           uint
+	}{
+		{4, 3, 14},
+		{5, 4, ExtendedSAR},
+		{1, 1, 1},
+	}
+	for _, tc := range testCases {
+		sps, err := ParseSPSNALUnit(byteData, true)
+		if err != nil {
+			t.Fatal(err)
+		}
+		sps.VUI.SampleAspectRatioWidth = tc.sarWidth
+		sps.VUI.SampleAspectRatioHeight = tc.sarHeight
+		data, err := EncodeSPSNALUnit(sps)
+		if err != nil {
+			t.Fatal(err)
+		}
+		gotSPS, err := ParseSPSNALUnit(data, true)
+		if err != nil {
+			t.Fatal(err)
+		}
+		vui := gotSPS.VUI
+		if vui.SampleAspectRatioWidth != tc.sarWidth || vui.SampleAspectRatioHeight != tc.sarHeight || vui.AspectRatioIDC != tc.wantedIDC {
+			t.Errorf("got SAR %d:%d with idc %d instead of %d:%d with idc %d", vui.SampleAspectRatioWidth,
+				vui.SampleAspectRatioHeight, vui.AspectRatioIDC, tc.sarWidth, tc.sarHeight, tc.wantedIDC)
+		}
+	}
+}
+
+func TestSPSFrameRate(t *testing.T) {
+	testCases := []struct {
+		spsHex  string
+		wantFPS float64
+	}{
+		{sps1nalu, 50},
+		{sps2nalu, 30},
+		{sps3nalu, 60},
+	}
+	for _, tc := range testCases {
+		byteData, _ := hex.DecodeString(tc.spsHex)
+		sps, err := ParseSPSNALUnit(byteData, true)
+		if err != nil {
+			t.Error(err)
+			continue
+		}
+		fps, ok := sps.FrameRate()
+		if !ok || fps != tc.wantFPS {
+			t.Errorf("got frame rate %f, %t instead of %f", fps, ok, tc.wantFPS)
+		}
+	}
+	byteData, _ := hex.DecodeString(sps1nalu)
+	sps, _ := ParseSPSNALUnit(byteData, false)
+	if _, ok := sps.FrameRate(); ok {
+		t.Error("expected no frame rate without timing info")
+	}
+	if sps.VUI.AspectRatioIDC != 1 {
+		t.Errorf("got aspect_ratio_idc %d instead of 1", sps.VUI.AspectRatioIDC)
+	}
+	fps, ok := (&VUIParameters{TimingInfoPresentFlag: true, NumUnitsInTick: 1001, TimeScale: 60000}).FrameRate()
+	if !ok || fps != 60000.0/2002.0 {
+		t.Errorf("got frame rate %f instead of 29.97", fps)
+	}
+}
+
+func TestHrdBitRateAndCpbSize(t *testing.T) {
+	testCases := []struct {
+		spsHex      string
+		wantBitRate uint64
+		wantCpbSize uint64
+	}{
+		{sps1nalu, 4400000, 4400000},
+		{sps3nalu, 5999616, 12000000},
+	}
+	for _, tc := range testCases {
+		byteData, _ := hex.DecodeString(tc.spsHex)
+		sps, err := ParseSPSNALUnit(byteData, true)
+		if err != nil {
+			t.Error(err)
+			continue
+		}
+		hrd := sps.VUI.NalHrdParameters
+		if got := hrd.MaxBitRate(); got != tc.wantBitRate {
+			t.Errorf("got max bit rate %d instead of %d", got, tc.wantBitRate)
+		}
+		if got := hrd.MaxCpbSize(); got != tc.wantCpbSize {
+			t.Errorf("got max CPB size %d instead of %d", got, tc.wantCpbSize)
+		}
+	}
+	hrd := HrdParameters{BitRateScale: 0, CpbSizeScale: 0, CpbEntries: []CpbEntry{{0, 1, false}, {1, 0, false}}}
+	if hrd.MaxBitRate() != 128 || hrd.MaxCpbSize() != 32 {
+		t.Errorf("got max bit rate %d and CPB size %d instead of 128 and 32", hrd.MaxBitRate(), hrd.MaxCpbSize())
+	}
+}
+
+func TestDisplayAspectRatio(t *testing.T) {
+	testCases := []struct {
+		sps          SPS
+		wantW, wantH uint
+	}{
+		{SPS{Width: 1280, Height: 720}, 16, 9},
+		{SPS{Width: 320, Height: 180, VUI: &VUIParameters{}}, 16, 9},
+		{SPS{Width: 1440, Height: 1080, VUI: &VUIParameters{SampleAspectRatioWidth: 4, SampleAspectRatioHeight: 3}}, 16, 9},
+		{SPS{Width: 720, Height: 576, VUI: &VUIParameters{SampleAspectRatioWidth: 16, SampleAspectRatioHeight: 11}}, 20, 11},
+		{SPS{}, 0, 0},
+	}
+	for _, tc := range testCases {
+		gotW, gotH := tc.sps.DisplayAspectRatio()
+		if gotW != tc.wantW || gotH != tc.wantH {
+			t.Errorf("got display aspect ratio %d:%d instead of %d:%d", gotW, gotH, tc.wantW, tc.wantH)
+		}
+	}
+}
+
+func TestProfileNameAndLevelString(t *testing.T) {
+	testCases := []struct {
+		profile, compatibility, level uint32
+		wantProfile, wantLevel        string
+	}{
+		{66, 0xc0, 30, "Constrained Baseline", "3.0"},
+		{66, 0x00, 11, "Baseline", "1.1"},
+		{66, 0x10, 11, "Baseline", "1b"},
+		{77, 0x40, 31, "Main", "3.1"},
+		{100, 0x00, 9, "High", "1b"},
+		{100, 0x10, 11, "High", "1.1"},
+		{110, 0x00, 40, "High 10", "4.0"},
+		{244, 0x00, 52, "High 4:4:4", "5.2"},
+		{1, 0x00, 10, "Unknown", "1.0"},
+	}
+	for _, tc := range testCases {
+		sps := SPS{Profile: tc.profile, ProfileCompatibility: tc.compatibility, Level: tc.level}
+		if got := sps.ProfileName(); got != tc.wantProfile {
+			t.Errorf("got profile name %q instead of %q", got, tc.wantProfile)
+		}
+		if got := sps.LevelString(); got != tc.wantLevel {
+			t.Errorf("got level %q instead of %q", got, tc.wantLevel)
+		}
+	}
+}
+
+func TestSPSPicOrderCntType1(t *testing.T) {
+	byteData, _ := hex.DecodeString(sps1nalu)
+	sps, err := ParseSPSNALUnit(byteData, true)
+	if err != nil {
+		t.Fatal(err)
+	}
+	sps.PicOrderCntType = 1
+	sps.Log2MaxPicOrderCntLsbMinus4 = 0
+	sps.OffsetForNonRefPic = -1
+	sps.OffsetForTopToBottomField = 1
+	sps.RefFramesInPicOrderCntCycle = []int{2, 2, -3}
+	data, err := EncodeSPSNALUnit(sps)
+	if err != nil {
+		t.Fatal(err)
+	}
+	gotSPS, err := ParseSPSNALUnit(data, true)
+	if err != nil {
+		t.Fatal(err)
+	}
+	if diff := deep.Equal(gotSPS, sps); diff != nil {
+		t.Error(diff)
+	}
+}
+
+func TestSPSParseErrors(t *testing.T) {
+	byteData, _ := hex.DecodeString(sps1nalu)
+	sps, err := ParseSPSNALUnit(byteData, true)
+	if err != nil {
+		t.Fatal(err)
+	}
+	sps.ChromaFormatIDC = 4
+	badChroma, err := EncodeSPSNALUnit(sps)
+	if err != nil {
+		t.Fatal(err)
+	}
+	sps.ChromaFormatIDC = 1
+	sps.PicOrderCntType = 1
+	sps.RefFramesInPicOrderCntCycle = make([]int, maxNumRefFramesInPicOrderCntCycle+1)
+	badPocCycle, err := EncodeSPSNALUnit(sps)
+	if err != nil {
+		t.Fatal(err)
+	}
+	testCases := []struct {
+		desc      string
+		data      []byte
+		wantErr   error
+		errPrefix string
+	}{
+		{"not SPS", []byte{0x68, 0xee}, ErrNotSPS, "not an SPS"},
+		{"EOF before VUI", byteData[:8], io.EOF, "sps: "},
+		{"EOF in VUI", byteData[:16], io.EOF, "sps vui: "},
+		{"invalid chroma_format_idc", badChroma, ErrInvalidChromaFormatIDC, "sps: "},
+		{"too long POC cycle", badPocCycle, ErrInvalidNumRefFramesInPicOrderCntCycle, "sps: "},
+	}
+	for _, tc := range testCases {
+		_, err := ParseSPSNALUnit(tc.data, true)
+		if !errors.Is(err, tc.wantErr) {
+			t.Errorf("%s: got error %v instead of %v", tc.desc, err, tc.wantErr)
+			continue
+		}
+		if !strings.HasPrefix(err.Error(), tc.errPrefix) {
+			t.Errorf("%s: error %q does not start with %q", tc.desc, err, tc.errPrefix)
+		}
+	}
+}
+
+func TestCropUnits(t *testing.T) {
+	testCases := []struct {
+		chromaFormatIDC         byte
+		separateColourPlaneFlag bool
+		frameMbsOnlyFlag        bool
+		wantedX, wantedY        uint
+	}{
+		{0, false, true, 1, 1},
+		{0, false, false, 1, 2},
+		{1, false, true, 2, 2},
+		{1, false, false, 2, 4},
+		{2, false, true, 2, 1},
+		{2, false, false, 2, 2},
+		{3, false, true, 1, 1},
+		{3, false, false, 1, 2},
+		{3, true, true, 1, 1},
+		{3, true, false, 1, 2},
+	}
+	for _, tc := range testCases {
+		sps := SPS{
+			ChromaFormatIDC:         tc.chromaFormatIDC,
+			SeparateColourPlaneFlag: tc.separateColourPlaneFlag,
+			FrameMbsOnlyFlag:        tc.frameMbsOnlyFlag,
+		}
+		gotX, gotY, err := sps.cropUnits()
+		if err != nil {
+			t.Error(err)
+		}
+		if gotX != tc.wantedX || gotY != tc.wantedY {
+			t.Errorf("%+v: got crop units %d, %d instead of %d, %d", tc, gotX, gotY, tc.wantedX, tc.wantedY)
+		}
+	}
+}
+
+func TestSPS422InterlacedCropping(t *testing.T) {
+	byteData, _ := hex.DecodeString(sps1nalu)
+	sps, err := ParseSPSNALUnit(byteData, true)
+	if err != nil {
+		t.Fatal(err)
+	}
+	// 1920x1088 coded interlaced 4:2:2 picture cropped with CropUnitX = 2 and CropUnitY = 2
+	sps.Profile = 122
+	sps.ChromaFormatIDC = 2
+	sps.FrameMbsOnlyFlag = false
+	sps.MbAdaptiveFrameFieldFlag = true
+	sps.FrameCroppingFlag = true
+	sps.FrameCropLeftOffset = 1
+	sps.FrameCropRightOffset = 3
+	sps.FrameCropTopOffset = 2
+	sps.FrameCropBottomOffset = 4
+	sps.Width = 1920 - (1+3)*2
+	sps.Height = 1088 - (2+4)*2
+	data, err := EncodeSPSNALUnit(sps)
+	if err != nil {
+		t.Fatal(err)
+	}
+	gotSPS, err := ParseSPSNALUnit(data, true)
+	if err != nil {
+		t.Fatal(err)
+	}
+	if gotSPS.Width != 1912 || gotSPS.Height != 1076 {
+		t.Errorf("got %dx%d instead of 1912x1076", gotSPS.Width, gotSPS.Height)
+	}
+	if diff := deep.Equal(gotSPS, sps); diff != nil {
+		t.Error(diff)
+	}
+}
+
+func TestSPSSeparateColourPlaneCropping(t *testing.T) {
+	byteData, _ := hex.DecodeString(sps1nalu)
+	sps, err := ParseSPSNALUnit(byteData, true)
+	if err != nil {
+		t.Fatal(err)
+	}
+	// 1920x1088 coded 4:4:4 picture with separate colour planes cropped with CropUnitX = 1 and CropUnitY = 1
+	sps.Profile = 244
+	sps.ChromaFormatIDC = 3
+	sps.SeparateColourPlaneFlag = true
+	sps.FrameCroppingFlag = true
+	sps.FrameCropLeftOffset = 0
+	sps.FrameCropRightOffset = 0
+	sps.FrameCropTopOffset = 0
+	sps.FrameCropBottomOffset = 8
+	sps.Width = 1920
+	sps.Height = 1088 - 8
+	data, err := EncodeSPSNALUnit(sps)
+	if err != nil {
+		t.Fatal(err)
+	}
+	gotSPS, err := ParseSPSNALUnit(data, true)
+	if err != nil {
+		t.Fatal(err)
+	}
+	if gotSPS.ChromaArrayType() != 0 {
+		t.Errorf("got ChromaArrayType %d instead of 0", gotSPS.ChromaArrayType())
+	}
+	if gotSPS.Width != 1920 || gotSPS.Height != 1080 {
+		t.Errorf("got %dx%d instead of 1920x1080", gotSPS.Width, gotSPS.Height)
+	}
+	if diff := deep.Equal(gotSPS, sps); diff != nil {
+		t.Error(diff)
+	}
+}
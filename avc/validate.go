@@ -0,0 +1,185 @@
+package avc
+
+import (
+	"bytes"
+	"fmt"
+
+	"github.com/Eyevinn/mp4ff/bits"
+)
+
+// ValidationKind - kind of problem found by ValidateSamples
+type ValidationKind int
+
+const (
+	// ValidationNoIDRStart - the first picture is not an IDR picture
+	ValidationNoIDRStart ValidationKind = iota + 1
+	// ValidationMixedIDR - IDR and non-IDR slices in the same sample
+	ValidationMixedIDR
+	// ValidationMissingParameterSets - slice before any SPS and PPS
+	ValidationMissingParameterSets
+	// ValidationUnknownParameterSet - slice refers to a PPS or SPS ID that has not been seen
+	ValidationUnknownParameterSet
+	// ValidationBadNALU - parameter set or slice header that cannot be parsed
+	ValidationBadNALU
+	// ValidationFrameNumGap - frame_num is not the previous reference frame_num or one more
+	ValidationFrameNumGap
+)
+
+func (k ValidationKind) String() string {
+	switch k {
+	case ValidationNoIDRStart:
+		return "no IDR start"
+	case ValidationMixedIDR:
+		return "mixed IDR"
+	case ValidationMissingParameterSets:
+		return "missing parameter sets"
+	case ValidationUnknownParameterSet:
+		return "unknown parameter set"
+	case ValidationBadNALU:
+		return "bad NAL unit"
+	case ValidationFrameNumGap:
+		return "frame_num gap"
+	default:
+		return fmt.Sprintf("unknown kind %d", int(k))
+	}
+}
+
+// ValidationWarning - problem in a sample found by ValidateSamples
+type ValidationWarning struct {
+	SampleNr int // one-based sample number
+	Kind     ValidationKind
+	Details  string
+}
+
+func (w ValidationWarning) String() string {
+	return fmt.Sprintf("sample %d: %s: %s", w.SampleNr, w.Kind, w.Details)
+}
+
+// ValidateSamples - check samples with 4-byte length-prefixed NAL units in decoding order for common problems.
+// spsNALUs and ppsNALUs are out-of-band parameter sets, e.g. from avcC, and may be nil.
+// Parameter sets inside the samples are used from where they appear.
+// The problems are returned as warnings, so all samples are checked.
+func ValidateSamples(samples [][]byte, spsNALUs, ppsNALUs [][]byte) []ValidationWarning {
+	var warnings []ValidationWarning
+	warn := func(sampleNr int, kind ValidationKind, format string, a ...interface{}) {
+		warnings = append(warnings, ValidationWarning{sampleNr, kind, fmt.Sprintf(format, a...)})
+	}
+	spsMap := make(map[uint32]*SPS)
+	ppsMap := make(map[uint32]*PPS)
+	addParameterSet := func(sampleNr int, nalu []byte) {
+		if len(nalu) == 0 {
+			warn(sampleNr, ValidationBadNALU, "empty parameter set NAL unit")
+			return
+		}
+		switch GetNaluType(nalu[0]) {
+		case NALU_SPS:
+			sps, err := ParseSPSNALUnit(nalu, false)
+			if err != nil {
+				warn(sampleNr, ValidationBadNALU, "%v", err)
+				return
+			}
+			spsMap[sps.ParameterID] = sps
+		case NALU_PPS:
+			pps, err := ParsePPSNALUnit(nalu, spsMap)
+			if err != nil {
+				warn(sampleNr, ValidationBadNALU, "%v", err)
+				return
+			}
+			ppsMap[pps.PicParameterSetID] = pps
+		}
+	}
+	for _, nalu := range spsNALUs {
+		addParameterSet(0, nalu)
+	}
+	for _, nalu := range ppsNALUs {
+		addParameterSet(0, nalu)
+	}
+
+	firstPicture := true
+	var prevRefFrameNum uint32
+	for i, sample := range samples {
+		sampleNr := i + 1
+		nalus, err := GetNalusFromSample(sample)
+		if err != nil {
+			warn(sampleNr, ValidationBadNALU, "%v", err)
+			continue
+		}
+		var firstSlice *SliceHeader
+		var firstSliceType NaluType
+		var nalRefIDC byte
+		for _, nalu := range nalus {
+			if len(nalu) == 0 {
+				continue
+			}
+			naluType := GetNaluType(nalu[0])
+			if naluType == NALU_SPS || naluType == NALU_PPS {
+				addParameterSet(sampleNr, nalu)
+				continue
+			}
+			if naluType != NALU_IDR && naluType != NALU_NON_IDR {
+				continue
+			}
+			if firstSlice != nil {
+				if naluType != firstSliceType {
+					warn(sampleNr, ValidationMixedIDR, "%s and %s slices", firstSliceType, naluType)
+				}
+				continue
+			}
+			if len(spsMap) == 0 || len(ppsMap) == 0 {
+				warn(sampleNr, ValidationMissingParameterSets, "%s slice before SPS and PPS", naluType)
+				break
+			}
+			ppsID := slicePPSID(nalu)
+			pps, ok := ppsMap[ppsID]
+			if !ok {
+				warn(sampleNr, ValidationUnknownParameterSet, "slice refers to unknown PPS ID %d", ppsID)
+				break
+			}
+			if _, ok := spsMap[pps.SeqParameterSetID]; !ok {
+				warn(sampleNr, ValidationUnknownParameterSet, "PPS %d refers to unknown SPS ID %d", ppsID, pps.SeqParameterSetID)
+				break
+			}
+			firstSlice, err = ParseSliceHeader(nalu, spsMap, ppsMap)
+			if err != nil {
+				warn(sampleNr, ValidationBadNALU, "slice header: %v", err)
+				break
+			}
+			firstSliceType = naluType
+			nalRefIDC = (nalu[0] >> 5) & 0x3
+		}
+		if firstSlice == nil {
+			continue
+		}
+		isIDR := firstSliceType == NALU_IDR
+		if firstPicture && !isIDR {
+			warn(sampleNr, ValidationNoIDRStart, "first picture is %s", firstSliceType)
+		}
+		sps := spsMap[ppsMap[firstSlice.PicParamID].SeqParameterSetID]
+		if !isIDR && !firstPicture && !sps.GapsInFrameNumValueAllowedFlag {
+			maxFrameNum := uint32(1) << (sps.Log2MaxFrameNumMinus4 + 4)
+			if firstSlice.FrameNum != prevRefFrameNum && firstSlice.FrameNum != (prevRefFrameNum+1)%maxFrameNum {
+				warn(sampleNr, ValidationFrameNumGap, "frame_num %d after reference frame_num %d",
+					firstSlice.FrameNum, prevRefFrameNum)
+			}
+		}
+		switch {
+		case isIDR:
+			prevRefFrameNum = 0
+		case nalRefIDC != 0:
+			prevRefFrameNum = firstSlice.FrameNum
+		}
+		if firstSlice.HasMMCO5 {
+			prevRefFrameNum = 0
+		}
+		firstPicture = false
+	}
+	return warnings
+}
+
+// slicePPSID - read pic_parameter_set_id from start of slice header
+func slicePPSID(nalu []byte) uint32 {
+	r := bits.NewEBSPReader(bytes.NewReader(nalu[1:]))
+	_ = r.ReadExpGolomb() // first_mb_in_slice
+	_ = r.ReadExpGolomb() // slice_type
+	return uint32(r.ReadExpGolomb())
+}
@@ -0,0 +1,69 @@
+package avc
+
+import (
+	"encoding/binary"
+	"os"
+	"testing"
+
+	"github.com/go-test/deep"
+)
+
+// readSamples - read Annex B file and make one length-prefixed sample per access unit starting with AUD
+func readSamples(t *testing.T, fileName string) [][]byte {
+	t.Helper()
+	data, err := os.ReadFile(fileName)
+	if err != nil {
+		t.Fatal(err)
+	}
+	var samples [][]byte
+	for _, nalu := range ExtractNalusFromByteStream(data) {
+		if GetNaluType(nalu[0]) == NALU_AUD {
+			samples = append(samples, nil)
+		}
+		i := len(samples) - 1
+		naluLength := make([]byte, 4)
+		binary.BigEndian.PutUint32(naluLength, uint32(len(nalu)))
+		samples[i] = append(samples[i], naluLength...)
+		samples[i] = append(samples[i], nalu...)
+	}
+	return samples
+}
+
+func TestValidateSamples(t *testing.T) {
+	samples := readSamples(t, "../cmd/mp4ff-nallister/testdata/4pics.264") // IDR and 3 P frames with frame_num 0-3
+	spss, ppss := GetParameterSets(samples[0])
+	testCases := []struct {
+		desc           string
+		samples        [][]byte
+		spss, ppss     [][]byte
+		wantedWarnings []ValidationWarning
+	}{
+		{desc: "all samples", samples: samples},
+		{desc: "missing sample", samples: [][]byte{samples[0], samples[2], samples[3]},
+			wantedWarnings: []ValidationWarning{
+				{SampleNr: 2, Kind: ValidationFrameNumGap, Details: "frame_num 2 after reference frame_num 0"},
+			}},
+		{desc: "no IDR start", samples: samples[1:],
+			wantedWarnings: []ValidationWarning{
+				{SampleNr: 1, Kind: ValidationMissingParameterSets, Details: "NonIDR_1 slice before SPS and PPS"},
+				{SampleNr: 2, Kind: ValidationMissingParameterSets, Details: "NonIDR_1 slice before SPS and PPS"},
+				{SampleNr: 3, Kind: ValidationMissingParameterSets, Details: "NonIDR_1 slice before SPS and PPS"},
+			}},
+		{desc: "no IDR start with parameter sets", samples: samples[1:], spss: spss, ppss: ppss,
+			wantedWarnings: []ValidationWarning{
+				{SampleNr: 1, Kind: ValidationNoIDRStart, Details: "first picture is NonIDR_1"},
+			}},
+		{desc: "empty out-of-band parameter set", samples: samples, spss: [][]byte{{}},
+			wantedWarnings: []ValidationWarning{
+				{SampleNr: 0, Kind: ValidationBadNALU, Details: "empty parameter set NAL unit"},
+			}},
+	}
+	for _, tc := range testCases {
+		t.Run(tc.desc, func(t *testing.T) {
+			got := ValidateSamples(tc.samples, tc.spss, tc.ppss)
+			if diff := deep.Equal(got, tc.wantedWarnings); diff != nil {
+				t.Error(diff)
+			}
+		})
+	}
+}
@@ -0,0 +1,40 @@
+package bits
+
+// RbspToEbsp - insert start-code emulation prevention bytes 0x03 where needed.
+// A 0x03 byte is inserted after two zero bytes if the next byte is 0x00, 0x01, 0x02, or 0x03.
+func RbspToEbsp(rbsp []byte) []byte {
+	ebsp := make([]byte, 0, len(rbsp)+len(rbsp)/64)
+	nr0 := 0
+	for _, b := range rbsp {
+		if nr0 == 2 && b <= 3 {
+			ebsp = append(ebsp, startCodeEmulationPreventionByte)
+			nr0 = 0
+		}
+		ebsp = append(ebsp, b)
+		if b == 0 {
+			nr0++
+		} else {
+			nr0 = 0
+		}
+	}
+	return ebsp
+}
+
+// EbspToRbsp - remove start-code emulation prevention bytes 0x03 following two zero bytes.
+func EbspToRbsp(ebsp []byte) []byte {
+	rbsp := make([]byte, 0, len(ebsp))
+	nr0 := 0
+	for _, b := range ebsp {
+		if nr0 == 2 && b == startCodeEmulationPreventionByte {
+			nr0 = 0
+			continue
+		}
+		rbsp = append(rbsp, b)
+		if b == 0 {
+			nr0++
+		} else {
+			nr0 = 0
+		}
+	}
+	return rbsp
+}
@@ -0,0 +1,41 @@
+package bits_test
+
+import (
+	"bytes"
+	"testing"
+
+	"github.com/Eyevinn/mp4ff/bits"
+)
+
+func TestRbspEbspConversion(t *testing.T) {
+	testCases := []struct {
+		desc string
+		rbsp []byte
+		ebsp []byte
+	}{
+		{"no zeros", []byte{0x01, 0x02, 0x03}, []byte{0x01, 0x02, 0x03}},
+		{"start code", []byte{0x00, 0x00, 0x01}, []byte{0x00, 0x00, 0x03, 0x01}},
+		{"three zeros", []byte{0x00, 0x00, 0x00, 0x00}, []byte{0x00, 0x00, 0x03, 0x00, 0x00}},
+		{"emulation byte in rbsp", []byte{0x00, 0x00, 0x03, 0x80}, []byte{0x00, 0x00, 0x03, 0x03, 0x80}},
+		{"no insertion before 0x04", []byte{0x00, 0x00, 0x04, 0x00, 0x00, 0x02}, []byte{0x00, 0x00, 0x04, 0x00, 0x00, 0x03, 0x02}},
+	}
+	for _, tc := range testCases {
+		gotEbsp := bits.RbspToEbsp(tc.rbsp)
+		if !bytes.Equal(gotEbsp, tc.ebsp) {
+			t.Errorf("%s: got ebsp %x instead of %x", tc.desc, gotEbsp, tc.ebsp)
+		}
+		gotRbsp := bits.EbspToRbsp(gotEbsp)
+		if !bytes.Equal(gotRbsp, tc.rbsp) {
+			t.Errorf("%s: got rbsp %x instead of %x", tc.desc, gotRbsp, tc.rbsp)
+		}
+		// Compare with EBSPWriter
+		buf := bytes.Buffer{}
+		w := bits.NewEBSPWriter(&buf)
+		for _, b := range tc.rbsp {
+			w.Write(uint(b), 8)
+		}
+		if !bytes.Equal(buf.Bytes(), tc.ebsp) {
+			t.Errorf("%s: EBSPWriter output %x differs from %x", tc.desc, buf.Bytes(), tc.ebsp)
+		}
+	}
+}
@@ -100,6 +100,16 @@ func (r *EBSPReader) Read(n int) uint {
 	return v
 }
 
+// ReadSigned reads a 2-complemented signed int with n bits.
+func (r *EBSPReader) ReadSigned(n int) int {
+	nr := int(r.Read(n))
+	firstBit := nr >> (n - 1)
+	if firstBit == 1 {
+		nr |= -1 << n
+	}
+	return nr
+}
+
 // ReadBytes read n bytes and return nil if new or accumulated error.
 func (r *EBSPReader) ReadBytes(n int) []byte {
 	if r.err != nil {
@@ -399,3 +399,20 @@ func TestEBSPReader(t *testing.T) {
 	})
 
 }
+
+func TestEBSPReaderSigned(t *testing.T) {
+	// 1100 0111 0000 0000 0000 0000 0000 0001 after removing emulation prevention byte 03
+	data := []byte{0xc7, 0x00, 0x00, 0x03, 0x01}
+	r := bits.NewEBSPReader(bytes.NewBuffer(data))
+	for i, tc := range []struct {
+		n    int
+		want int
+	}{{4, -4}, {4, 7}, {16, 0}, {5, 0}, {3, 1}} {
+		if got := r.ReadSigned(tc.n); got != tc.want {
+			t.Errorf("case %d: ReadSigned(%d)=%d, want=%d", i, tc.n, got, tc.want)
+		}
+	}
+	if r.AccError() != nil {
+		t.Errorf("unexpected error: %v", r.AccError())
+	}
+}
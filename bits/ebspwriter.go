@@ -82,6 +82,24 @@ func (w *EBSPWriter) WriteExpGolomb(nr uint) {
 	}
 }
 
+// WriteSignedGolomb - write a signed exponential Golomb code
+func (w *EBSPWriter) WriteSignedGolomb(nr int) {
+	if nr > 0 {
+		w.WriteExpGolomb(uint(2*nr - 1))
+	} else {
+		w.WriteExpGolomb(uint(-2 * nr))
+	}
+}
+
+// WriteFlag - write a flag as one bit
+func (w *EBSPWriter) WriteFlag(f bool) {
+	if f {
+		w.Write(1, 1)
+	} else {
+		w.Write(0, 1)
+	}
+}
+
 // WriteSEIValue insert 0xFF until value is less than 255. Used in SEI payload type and size.
 func (w *EBSPWriter) WriteSEIValue(val uint) {
 	for {
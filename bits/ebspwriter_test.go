@@ -76,6 +76,37 @@ func TestEBSPWriter(t *testing.T) {
 		}
 	})
 
+	t.Run("write signed exp golomb and flags", func(t *testing.T) {
+		cases := []struct {
+			bits string
+			n    int
+		}{
+			{"1", 0},
+			{"010", 1},
+			{"011", -1},
+			{"00100", 2},
+			{"00101", -2},
+			{"0001111", -7},
+		}
+		for _, tc := range cases {
+			b := bytes.Buffer{}
+			w := bits.NewEBSPWriter(&b)
+			w.WriteSignedGolomb(tc.n)
+			gotBits := getBitsWritten(w, &b)
+			if gotBits != tc.bits {
+				t.Errorf("wanted %s but got %s for %d", tc.bits, gotBits, tc.n)
+			}
+		}
+		b := bytes.Buffer{}
+		w := bits.NewEBSPWriter(&b)
+		w.WriteFlag(true)
+		w.WriteFlag(false)
+		w.WriteFlag(true)
+		if gotBits := getBitsWritten(w, &b); gotBits != "101" {
+			t.Errorf("wanted 101 but got %s", gotBits)
+		}
+	})
+
 	t.Run("write to limited writer", func(t *testing.T) {
 		lw := newLimitedWriter(3)
 		w := bits.NewEBSPWriter(lw)
@@ -137,6 +168,34 @@ func TestEBSPWriter(t *testing.T) {
 	})
 }
 
+func TestEBSPWriterGolombRoundTrip(t *testing.T) {
+	b := bytes.Buffer{}
+	w := bits.NewEBSPWriter(&b)
+	for n := 0; n < 1000; n++ {
+		w.WriteExpGolomb(uint(n))
+		w.WriteSignedGolomb(n - 500)
+	}
+	w.WriteRbspTrailingBits()
+	if w.AccError() != nil {
+		t.Fatalf("unexpected write error: %v", w.AccError())
+	}
+	r := bits.NewEBSPReader(bytes.NewReader(b.Bytes()))
+	for n := 0; n < 1000; n++ {
+		if got := r.ReadExpGolomb(); got != uint(n) {
+			t.Fatalf("read ue %d instead of %d", got, n)
+		}
+		if got := r.ReadSignedGolomb(); got != n-500 {
+			t.Fatalf("read se %d instead of %d", got, n-500)
+		}
+	}
+	if err := r.ReadRbspTrailingBits(); err != nil {
+		t.Error(err)
+	}
+	if r.AccError() != nil {
+		t.Errorf("unexpected read error: %v", r.AccError())
+	}
+}
+
 func getBitsWritten(w *bits.EBSPWriter, b *bytes.Buffer) string {
 	bits := ""
 	for _, c := range b.Bytes() {
@@ -10,3 +10,20 @@ func CeilLog2(n uint) int {
 	}
 	return 32
 }
+
+// ExpGolombBitLength returns nr bits needed to write n as unsigned exp-Golomb code ue(v).
+func ExpGolombBitLength(n uint) int {
+	prefixLen := 0
+	for v := n + 1; v > 1; v >>= 1 {
+		prefixLen++
+	}
+	return 2*prefixLen + 1
+}
+
+// SignedGolombBitLength returns nr bits needed to write n as signed exp-Golomb code se(v).
+func SignedGolombBitLength(n int) int {
+	if n > 0 {
+		return ExpGolombBitLength(uint(2*n - 1))
+	}
+	return ExpGolombBitLength(uint(-2 * n))
+}
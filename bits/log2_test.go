@@ -25,3 +25,43 @@ func TestCeilLog2(t *testing.T) {
 		}
 	}
 }
+
+func TestExpGolombBitLength(t *testing.T) {
+	testCases := []struct {
+		n    uint
+		want int
+	}{
+		{0, 1},
+		{1, 3},
+		{2, 3},
+		{3, 5},
+		{6, 5},
+		{7, 7},
+		{254, 15},
+		{255, 17},
+	}
+	for _, tc := range testCases {
+		if got := bits.ExpGolombBitLength(tc.n); got != tc.want {
+			t.Errorf("ExpGolombBitLength(%d) = %d, want %d", tc.n, got, tc.want)
+		}
+	}
+}
+
+func TestSignedGolombBitLength(t *testing.T) {
+	testCases := []struct {
+		n    int
+		want int
+	}{
+		{0, 1},
+		{1, 3},
+		{-1, 3},
+		{2, 5},
+		{-2, 5},
+		{-7, 7},
+	}
+	for _, tc := range testCases {
+		if got := bits.SignedGolombBitLength(tc.n); got != tc.want {
+			t.Errorf("SignedGolombBitLength(%d) = %d, want %d", tc.n, got, tc.want)
+		}
+	}
+}
@@ -140,6 +140,46 @@ func TestAccErrReaderSigned(t *testing.T) {
 	}
 }
 
+func TestAccErrReaderGolomb(t *testing.T) {
+	// 1 010 011 00100 00101 0001001 = ue 0, 1, 2, 3, se -2, -4
+	input := []byte{0xa6, 0x42, 0x89, 0x00}
+	reader := bits.NewReader(bytes.NewReader(input))
+	for i, want := range []uint{0, 1, 2, 3} {
+		if got := reader.ReadExpGolomb(); got != want {
+			t.Errorf("ue %d: got %d, want %d", i, got, want)
+		}
+	}
+	for i, want := range []int{-2, -4} {
+		if got := reader.ReadSignedGolomb(); got != want {
+			t.Errorf("se %d: got %d, want %d", i, got, want)
+		}
+	}
+	if err := reader.AccError(); err != nil {
+		t.Errorf("Got accumulated error: %s", err.Error())
+	}
+	_ = reader.ReadExpGolomb() // Only zero bits left
+	if reader.AccError() != io.EOF {
+		t.Errorf("Wanted io.EOF but got %v", reader.AccError())
+	}
+}
+
+func TestAccErrReaderBytes(t *testing.T) {
+	input := []byte{0x81, 0x02, 0x03}
+	reader := bits.NewReader(bytes.NewReader(input))
+	_ = reader.ReadFlag()
+	got := reader.ReadBytes(2)
+	want := []byte{0x02, 0x04}
+	if !bytes.Equal(got, want) {
+		t.Errorf("ReadBytes(2)=%x, want=%x", got, want)
+	}
+	if got := reader.ReadBytes(1); got != nil {
+		t.Errorf("ReadBytes(1)=%x beyond end, want nil", got)
+	}
+	if reader.AccError() != io.EOF {
+		t.Errorf("Wanted io.EOF but got %v", reader.AccError())
+	}
+}
+
 func TestBadAccErrReader(t *testing.T) {
 	// Check that reading beyond EOF provides value = 0 after acc error
 	input := []byte{0xff, 0x0f} // 1111 1111 0000 1111
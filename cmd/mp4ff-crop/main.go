@@ -479,6 +479,10 @@ func cropStsc(b *mp4.StscBox, lastSampleNr uint32) error {
 	samplesLeft := lastSampleNr - lastEntry.FirstSampleNr + 1
 	nrChunksInLast := samplesLeft / lastEntry.SamplesPerChunk
 	nrLeft := samplesLeft - nrChunksInLast*lastEntry.SamplesPerChunk
+	if nrLeft > 0 && nrChunksInLast == 0 { // Only part of the entry's first chunk is left
+		b.Entries[entryIdx].SamplesPerChunk = nrLeft
+		return nil
+	}
 	if nrLeft > 0 {
 		sdid := b.GetSampleDescriptionID(int(lastEntry.FirstChunk))
 		err := b.AddEntry(lastEntry.FirstChunk+nrChunksInLast, nrLeft, sdid)
@@ -2,8 +2,10 @@
 mp4ff-nallister lists NAL units and slice types of AVC or HEVC tracks of an mp4 (ISOBMFF) file
 or a file containing a byte stream in Annex B format.
 
-Takes first video track in a progressive file and the first track in a fragmented file.
+Takes first video track in a progressive file and the first track in a fragmented file,
+unless a track is chosen by its track ID with the -t option.
 It can also output information about SEI NAL units.
+A file starting with a start code is treated as Annex B even without the -annexb option.
 
 The parameter-sets can be further
 analyzed using mp4ff-pslister.
@@ -26,6 +28,8 @@ analyzed using mp4ff-pslister.
 			nr raw NAL unit bytes to print
 		-sei int
 			Level of SEI information (1 is interpret, 2 is dump hex)
+		-t int
+			Track ID of video track to parse (0 is first video track)
 		-version
 			Get mp4ff version
 */
@@ -23,8 +23,10 @@ const (
 var usg = `%s lists NAL units and slice types of AVC or HEVC tracks of an mp4 (ISOBMFF) file
 or a file containing a byte stream in Annex B format.
 
-Takes first video track in a progressive file and the first track in a fragmented file.
+Takes first video track in a progressive file and the first track in a fragmented file,
+unless a track is chosen by its track ID with the -t option.
 It can also output information about SEI NAL units.
+A file starting with a start code is treated as Annex B even without the -annexb option.
 
 The parameter-sets can be further analyzed using mp4ff-pslister.
 
@@ -33,6 +35,7 @@ Usage of %s:
 
 type options struct {
 	maxNrSamples int
+	trackID      int
 	codec        string
 	seiLevel     int
 	printRaw     int
@@ -51,6 +54,7 @@ func parseOptions(fs *flag.FlagSet, args []string) (*options, error) {
 	opts := options{}
 
 	fs.IntVar(&opts.maxNrSamples, "m", -1, "Max nr of samples to parse")
+	fs.IntVar(&opts.trackID, "t", 0, "Track ID of video track to parse (0 is first video track)")
 	fs.StringVar(&opts.codec, "c", "avc", "Codec to parse (avc or hevc)")
 	fs.IntVar(&opts.seiLevel, "sei", 0, "Level of SEI information (1 is interpret, 2 is dump hex)")
 	fs.IntVar(&opts.printRaw, "raw", 0, "nr raw NAL unit bytes to print")
@@ -92,7 +96,7 @@ func run(args []string, stdout io.Writer) error {
 	inFilePath := fs.Arg(0)
 
 	// First try to handle Annex B file
-	if o.annexB {
+	if o.annexB || hasStartCode(inFilePath) {
 		data, err := os.ReadFile(inFilePath)
 		if err != nil {
 			return fmt.Errorf("error reading file: %w", err)
@@ -143,27 +147,54 @@ func run(args []string, stdout io.Writer) error {
 	// Need to handle progressive files as well as fragmented files
 
 	if !parsedMp4.IsFragmented() {
-		err = parseProgressiveMp4(stdout, parsedMp4, o.maxNrSamples, o.codec, o.seiLevel, o.printPsHex, o.printRaw)
+		err = parseProgressiveMp4(stdout, parsedMp4, o)
 		if err != nil {
 			return fmt.Errorf("error parsing progressive file: %w", err)
 		}
 		return nil
 	}
-	err = parseFragmentedMp4(stdout, parsedMp4, o.maxNrSamples, o.codec, o.seiLevel, o.printPsHex, o.printRaw)
+	err = parseFragmentedMp4(stdout, parsedMp4, o)
 	if err != nil {
 		return fmt.Errorf("error parsing fragmented file: %w", err)
 	}
 	return nil
 }
 
-func parseProgressiveMp4(w io.Writer, f *mp4.File, maxNrSamples int, codec string, seiLevel int, parameterSets bool, nrRaw int) error {
-	videoTrak, ok := findFirstVideoTrak(f.Moov)
-	if !ok {
-		return fmt.Errorf("no video track found")
+// hasStartCode - check if file starts with an Annex B start code 0x000001 or 0x00000001.
+// A box size of 256-511 bytes also starts with 0x000001, so a following box type is not a start code.
+func hasStartCode(filePath string) bool {
+	fh, err := os.Open(filePath)
+	if err != nil {
+		return false
+	}
+	defer fh.Close()
+	start := make([]byte, 8)
+	n, _ := io.ReadFull(fh, start)
+	start = start[:n]
+	if len(start) == 8 && isBoxType(start[4:8]) {
+		return false
+	}
+	return bytes.HasPrefix(start, []byte{0, 0, 1}) || bytes.HasPrefix(start, []byte{0, 0, 0, 1})
+}
+
+// isBoxType - check if all bytes are printable ASCII as in a box type
+func isBoxType(b []byte) bool {
+	for _, c := range b {
+		if c < 0x20 || c > 0x7e {
+			return false
+		}
+	}
+	return true
+}
+
+func parseProgressiveMp4(w io.Writer, f *mp4.File, o *options) error {
+	videoTrak, err := findVideoTrak(f.Moov, uint32(o.trackID))
+	if err != nil {
+		return err
 	}
 
+	codec := o.codec
 	var avcSPS *avc.SPS
-	var err error
 	stbl := videoTrak.Mdia.Minf.Stbl
 	if stbl.Stsd.AvcX != nil {
 		codec = "avc"
@@ -177,30 +208,26 @@ func parseProgressiveMp4(w io.Writer, f *mp4.File, maxNrSamples int, codec strin
 		codec = "hevc"
 	}
 	nrSamples := stbl.Stsz.SampleNumber
+	if nrSamples == 0 {
+		return nil
+	}
+	sampleRanges, err := videoTrak.GetSampleRanges(1, nrSamples)
+	if err != nil {
+		return err
+	}
 	mdat := f.Mdat
 	mdatPayloadStart := mdat.PayloadAbsoluteOffset()
 
 	for sampleNr := 1; sampleNr <= int(nrSamples); sampleNr++ {
-		chunkNr, sampleNrAtChunkStart, err := stbl.Stsc.ChunkNrFromSampleNr(sampleNr)
-		if err != nil {
-			return err
-		}
-		offset, err := getChunkOffset(stbl, chunkNr)
-		if err != nil {
-			return err
-		}
-		for sNr := sampleNrAtChunkStart; sNr < sampleNr; sNr++ {
-			offset += int64(stbl.Stsz.GetSampleSize(sNr))
-		}
-		size := stbl.Stsz.GetSampleSize(sampleNr)
+		sampleRange := sampleRanges[sampleNr-1]
 		decTime, _ := stbl.Stts.GetDecodeTime(uint32(sampleNr))
 		var cto int32 = 0
 		if stbl.Ctts != nil {
 			cto = stbl.Ctts.GetCompositionTimeOffset(uint32(sampleNr))
 		}
 		// Next find sample bytes as slice in mdat
-		offsetInMdatData := uint64(offset) - mdatPayloadStart
-		sample := mdat.Data[offsetInMdatData : offsetInMdatData+uint64(size)]
+		offsetInMdatData := sampleRange.Offset - mdatPayloadStart
+		sample := mdat.Data[offsetInMdatData : offsetInMdatData+sampleRange.Size]
 		nalus, err := avc.GetNalusFromSample(sample)
 		if err != nil {
 			return err
@@ -217,52 +244,55 @@ func parseProgressiveMp4(w io.Writer, f *mp4.File, maxNrSamples int, codec strin
 					}
 				}
 			}
-			err = printAVCNalus(w, avcSPS, nalus, sampleNr, decTime+uint64(cto), seiLevel, parameterSets, nrRaw)
+			err = printAVCNalus(w, avcSPS, nalus, sampleNr, decTime+uint64(cto), o.seiLevel, o.printPsHex, o.printRaw)
 		case "hevc", "h.265", "h265":
-			err = printHEVCNalus(w, nalus, sampleNr, decTime+uint64(cto), seiLevel, parameterSets, nrRaw)
+			err = printHEVCNalus(w, nalus, sampleNr, decTime+uint64(cto), o.seiLevel, o.printPsHex, o.printRaw)
 		default:
 			return fmt.Errorf("unknown codec: %s", codec)
 		}
 		if err != nil {
 			return err
 		}
-		if sampleNr == maxNrSamples {
+		if sampleNr == o.maxNrSamples {
 			break
 		}
 	}
 	return nil
 }
 
-func findFirstVideoTrak(moov *mp4.MoovBox) (*mp4.TrakBox, bool) {
+// findVideoTrak - find video track with trackID, or the first video track if trackID is 0
+func findVideoTrak(moov *mp4.MoovBox, trackID uint32) (*mp4.TrakBox, error) {
+	if trackID != 0 {
+		trak, ok := moov.GetTrak(trackID)
+		if !ok {
+			return nil, fmt.Errorf("no track with trackID %d", trackID)
+		}
+		if hdlrType := trak.Mdia.Hdlr.HandlerType; hdlrType != "vide" {
+			return nil, fmt.Errorf("track with trackID %d is not a video track but %s", trackID, hdlrType)
+		}
+		return trak, nil
+	}
 	for _, inTrak := range moov.Traks {
 		hdlrType := inTrak.Mdia.Hdlr.HandlerType
 		if hdlrType != "vide" {
 			continue
 		}
-		return inTrak, true
-	}
-	return nil, false
-}
-
-func getChunkOffset(stbl *mp4.StblBox, chunkNr int) (int64, error) {
-	if stbl.Stco != nil {
-		return int64(stbl.Stco.ChunkOffset[chunkNr-1]), nil
+		return inTrak, nil
 	}
-	if stbl.Co64 != nil {
-		return int64(stbl.Co64.ChunkOffset[chunkNr-1]), nil
-	}
-	return 0, fmt.Errorf("neither stco nor co64 is present")
+	return nil, fmt.Errorf("no video track found")
 }
 
-func parseFragmentedMp4(w io.Writer, f *mp4.File, maxNrSamples int, codec string, seiLevel int, parameterSets bool, nrRaw int) error {
+func parseFragmentedMp4(w io.Writer, f *mp4.File, o *options) error {
 	var trex *mp4.TrexBox
 	var avcSPS *avc.SPS
 	var err error
+	codec := o.codec
 	if f.Init != nil { // Auto-detect codec if moov box is there
 		moov := f.Init.Moov
-		videoTrak, ok := findFirstVideoTrak(moov)
-		if !ok {
-			return fmt.Errorf("no video track found")
+		var videoTrak *mp4.TrakBox
+		videoTrak, err = findVideoTrak(moov, uint32(o.trackID))
+		if err != nil {
+			return err
 		}
 		stbl := videoTrak.Mdia.Minf.Stbl
 		if stbl.Stsd.AvcX != nil {
@@ -277,6 +307,8 @@ func parseFragmentedMp4(w io.Writer, f *mp4.File, maxNrSamples int, codec string
 			codec = "hevc"
 		}
 		trex, _ = moov.Mvex.GetTrex(videoTrak.Tkhd.TrackID)
+	} else if o.trackID != 0 { // Only the trackID is needed to select the track fragments
+		trex = &mp4.TrexBox{TrackID: uint32(o.trackID)}
 	}
 	iSamples := make([]mp4.FullSample, 0)
 	for _, iSeg := range f.Segments {
@@ -295,9 +327,9 @@ func parseFragmentedMp4(w io.Writer, f *mp4.File, maxNrSamples int, codec string
 		}
 		switch codec {
 		case "avc", "h.264", "h264":
-			err = printAVCNalus(w, avcSPS, nalus, i+1, s.PresentationTime(), seiLevel, parameterSets, nrRaw)
+			err = printAVCNalus(w, avcSPS, nalus, i+1, s.PresentationTime(), o.seiLevel, o.printPsHex, o.printRaw)
 		case "hevc", "h.265", "h265":
-			err = printHEVCNalus(w, nalus, i+1, s.PresentationTime(), seiLevel, parameterSets, nrRaw)
+			err = printHEVCNalus(w, nalus, i+1, s.PresentationTime(), o.seiLevel, o.printPsHex, o.printRaw)
 		default:
 			return fmt.Errorf("unknown codec: %s", codec)
 		}
@@ -305,7 +337,7 @@ func parseFragmentedMp4(w io.Writer, f *mp4.File, maxNrSamples int, codec string
 		if err != nil {
 			return err
 		}
-		if i+1 == maxNrSamples {
+		if i+1 == o.maxNrSamples {
 			break
 		}
 	}
@@ -2,7 +2,9 @@ package main
 
 import (
 	"bytes"
+	"encoding/binary"
 	"os"
+	"path"
 	"testing"
 )
 
@@ -21,11 +23,17 @@ func TestOptions(t *testing.T) {
 		{desc: "annexB, non-existing file", args: []string{appName, "-annexb", "none.264"}, expectedErr: true},
 		{desc: "annexBH264", args: []string{appName, "-annexb", "-ps", "testdata/4pics.264"},
 			goldenOut: "testdata/golden_4pics_h264.txt", expectedErr: false},
+		{desc: "annexBH264 detected", args: []string{appName, "-ps", "testdata/4pics.264"},
+			goldenOut: "testdata/golden_4pics_h264.txt", expectedErr: false},
 		{desc: "annexBBadCodec", args: []string{appName, "-annexb", "-c", "av1", "testdata/4pics.264"},
 			expectedErr: true},
 		{desc: "initFile", args: []string{appName, "../../mp4/testdata/init.mp4"}, expectedErr: false},
 		{desc: "progH264", args: []string{appName, "-ps", "-m", "4", "../../mp4/testdata/prog_8s.mp4"},
 			goldenOut: "testdata/golden_prot_h264_4pics.txt", expectedErr: false},
+		{desc: "progH264 trackID", args: []string{appName, "-ps", "-m", "4", "-t", "2", "../../mp4/testdata/prog_8s.mp4"},
+			goldenOut: "testdata/golden_prot_h264_4pics.txt", expectedErr: false},
+		{desc: "prog audio trackID", args: []string{appName, "-t", "1", "../../mp4/testdata/prog_8s.mp4"}, expectedErr: true},
+		{desc: "prog bad trackID", args: []string{appName, "-t", "3", "../../mp4/testdata/prog_8s.mp4"}, expectedErr: true},
 		{desc: "mp4H264", args: []string{appName, "testdata/h264.mp4"},
 			goldenOut: "testdata/golden_h264_mp4.txt", expectedErr: false},
 		{desc: "annexBHEVC", args: []string{appName, "-annexb", "-c", "hevc", "testdata/hevc.265"},
@@ -36,6 +44,10 @@ func TestOptions(t *testing.T) {
 			goldenOut: "testdata/golden_hevc_mp4.txt", expectedErr: false},
 		{desc: "h264 frag mp4 raw", args: []string{appName, "-m", "6", "-raw", "4", "../../mp4/testdata/prog_8s_dec_dashinit.mp4"},
 			goldenOut: "testdata/golden_h264_frag_raw.txt", expectedErr: false},
+		{desc: "h264 frag mp4 raw trackID",
+			args:      []string{appName, "-m", "6", "-raw", "4", "-t", "2", "../../mp4/testdata/prog_8s_dec_dashinit.mp4"},
+			goldenOut: "testdata/golden_h264_frag_raw.txt", expectedErr: false},
+		{desc: "frag bad trackID", args: []string{appName, "-t", "3", "../../mp4/testdata/prog_8s_dec_dashinit.mp4"}, expectedErr: true},
 		{desc: "avcSeiTime", args: []string{appName, "-sei", "2", "-annexb", "testdata/4pics.264"},
 			goldenOut: "testdata/golden_4pic_sei_264.txt", expectedErr: false},
 		{desc: "version", args: []string{appName, "-version"}, expectedErr: false},
@@ -75,3 +87,37 @@ func getExpected(t *testing.T, filename string) string {
 	r := bytes.ReplaceAll(b, []byte("\r\n"), []byte("\n"))
 	return string(r)
 }
+
+// TestMP4WithStartCodeLikeSize - an MP4 file with a first box of 256-511 bytes starts with 00 00 01,
+// but must not be treated as Annex B
+func TestMP4WithStartCodeLikeSize(t *testing.T) {
+	data, err := os.ReadFile("../../mp4/testdata/prog_8s_dec_dashinit.mp4")
+	if err != nil {
+		t.Fatal(err)
+	}
+	ftypSize := binary.BigEndian.Uint32(data[0:4])
+	// Make a 288-byte ftyp box by adding compatible brands. The fragments use default-base-is-moof,
+	// so the sample data offsets do not change.
+	bigFtyp := make([]byte, 16, 288)
+	binary.BigEndian.PutUint32(bigFtyp[0:4], 288)
+	copy(bigFtyp[4:16], data[4:16])
+	for len(bigFtyp) < 288 {
+		bigFtyp = append(bigFtyp, "iso6"...)
+	}
+	inPath := path.Join(t.TempDir(), "big_ftyp.mp4")
+	err = os.WriteFile(inPath, append(bigFtyp, data[ftypSize:]...), 0o644)
+	if err != nil {
+		t.Fatal(err)
+	}
+	if hasStartCode(inPath) {
+		t.Error("MP4 file with 288-byte ftyp detected as Annex B")
+	}
+	gotOut := bytes.Buffer{}
+	err = run([]string{appName, "-m", "6", "-raw", "4", inPath}, &gotOut)
+	if err != nil {
+		t.Fatal(err)
+	}
+	if expected := getExpected(t, "testdata/golden_h264_frag_raw.txt"); gotOut.String() != expected {
+		t.Errorf("expected %s, got %s", expected, gotOut.String())
+	}
+}
@@ -230,17 +230,29 @@ func parseMp4File(w io.Writer, r io.Reader, codec string, verbose bool) error {
 	for _, trak := range parsedMp4.Moov.Traks {
 		if trak.Tkhd.TrackID == trackID {
 			stbl := trak.Mdia.Minf.Stbl
-			var offset int64
-			if stbl.Stco != nil {
-				offset = int64(stbl.Stco.ChunkOffset[0])
-			} else if stbl.Co64 != nil {
-				offset = int64(stbl.Co64.ChunkOffset[0])
+			var offset uint64
+			switch {
+			case stbl.Stco != nil:
+				offset, err = stbl.Stco.GetOffset(1)
+			case stbl.Co64 != nil:
+				offset, err = stbl.Co64.GetOffset(1)
+			default:
+				err = fmt.Errorf("neither stco nor co64 available")
+			}
+			if err != nil {
+				return err
 			}
 			size := stbl.Stsz.GetSampleSize(1)
 			// Next find bytes as slice in mdat
 			mdat := parsedMp4.Mdat
+			if mdat == nil {
+				return fmt.Errorf("no mdat box")
+			}
 			mdatPayloadStart := mdat.PayloadAbsoluteOffset()
-			offsetInMdatData := uint64(offset) - mdatPayloadStart
+			if offset < mdatPayloadStart || offset-mdatPayloadStart+uint64(size) > uint64(len(mdat.Data)) {
+				return fmt.Errorf("first sample not inside mdat")
+			}
+			offsetInMdatData := offset - mdatPayloadStart
 			sampleData := mdat.Data[offsetInMdatData : offsetInMdatData+uint64(size)]
 			switch codec {
 			case "avc":
@@ -1,7 +1,8 @@
 /*
-mp4ff-subslister lists and displays content of wvtt or stpp samples.
-These corresponds to WebVTT or TTML subtitles in ISOBMFF files.
-Uses track with given non-zero track ID or first subtitle track found in an asset.
+mp4ff-subslister lists and displays content of wvtt, stpp, or tx3g samples.
+These corresponds to WebVTT, TTML, or 3GPP timed text subtitles in ISOBMFF files.
+Uses track with given non-zero track ID, or first subtitle track found in an asset with given language, if any.
+All subtitle tracks with their languages can be listed with -list.
 
 	Usage of mp4ff-subslister:
 
@@ -9,10 +10,22 @@ Uses track with given non-zero track ID or first subtitle track found in an asse
 
 	options:
 
+		-framerate string
+				Snap sample times to the frame grid of a frame rate like 25 or 30000/1001
+		-lang string
+				Three-letter language code of track to extract, e.g. eng (empty is unspecified)
+		-list
+				List track ID, type, and language of all subtitle tracks
 		-m int
 				Max nr of samples to parse (default -1)
+		-srt
+				Output wvtt, stpp, or tx3g cues in SRT (SubRip) format
 		-t int
 				trackID to extract (0 is unspecified)
+		-timescale int
+				Timescale for sample times and durations, e.g. 1000 or 90000 (0 is track timescale)
+		-timing
+				Only list sample times and durations, not the content
 		-version
 				Get mp4ff version
 */
@@ -7,6 +7,9 @@ import (
 	"fmt"
 	"io"
 	"os"
+	"regexp"
+	"strconv"
+	"strings"
 
 	"github.com/Eyevinn/mp4ff/internal"
 	"github.com/Eyevinn/mp4ff/mp4"
@@ -16,9 +19,10 @@ const (
 	appName = "mp4ff-subslister"
 )
 
-var usg = `%s lists and displays content of wvtt or stpp samples.
-These corresponds to WebVTT or TTML subtitles in ISOBMFF files.
-Uses track with given non-zero track ID or first subtitle track found in an asset.
+var usg = `%s lists and displays content of wvtt, stpp, or tx3g samples.
+These corresponds to WebVTT, TTML, or 3GPP timed text subtitles in ISOBMFF files.
+Uses track with given non-zero track ID, or first subtitle track found in an asset with given language, if any.
+All subtitle tracks with their languages can be listed with -list.
 
 Usage of %s:
 `
@@ -26,6 +30,14 @@ Usage of %s:
 type options struct {
 	maxNrSamples int
 	trackID      int
+	lang         string
+	list         bool
+	timingOnly   bool
+	timescale    int
+	frameRate    string
+	frameRateNum uint64 // parsed from frameRate
+	frameRateDen uint64
+	srt          bool
 	version      bool
 }
 
@@ -40,6 +52,12 @@ func parseOptions(fs *flag.FlagSet, args []string) (*options, error) {
 
 	fs.IntVar(&opts.maxNrSamples, "m", -1, "Max nr of samples to parse")
 	fs.IntVar(&opts.trackID, "t", 0, "trackID to extract (0 is unspecified)")
+	fs.StringVar(&opts.lang, "lang", "", "Three-letter language code of track to extract, e.g. eng (empty is unspecified)")
+	fs.BoolVar(&opts.list, "list", false, "List track ID, type, and language of all subtitle tracks")
+	fs.BoolVar(&opts.timingOnly, "timing", false, "Only list sample times and durations, not the content")
+	fs.IntVar(&opts.timescale, "timescale", 0, "Timescale for sample times and durations, e.g. 1000 or 90000 (0 is track timescale)")
+	fs.StringVar(&opts.frameRate, "framerate", "", "Snap sample times to the frame grid of a frame rate like 25 or 30000/1001")
+	fs.BoolVar(&opts.srt, "srt", false, "Output wvtt, stpp, or tx3g cues in SRT (SubRip) format")
 	fs.BoolVar(&opts.version, "version", false, "Get mp4ff version")
 
 	err := fs.Parse(args[1:])
@@ -74,6 +92,17 @@ func run(args []string, stdout io.Writer) error {
 		return fmt.Errorf("missing input file")
 	}
 
+	if o.srt {
+		if o.timingOnly {
+			return fmt.Errorf("-srt cannot be combined with -timing")
+		}
+		o.timescale = 1000 // SRT times are in milliseconds
+	}
+	o.frameRateNum, o.frameRateDen, err = parseFrameRate(o.frameRate)
+	if err != nil {
+		return err
+	}
+
 	inFilePath := fs.Arg(0)
 
 	ifd, err := os.Open(inFilePath)
@@ -87,8 +116,12 @@ func run(args []string, stdout io.Writer) error {
 		return err
 	}
 
+	if o.list {
+		return listSubtitleTracks(parsedMp4.Moov, stdout)
+	}
+
 	if !parsedMp4.IsFragmented() { // Progressive file
-		err = parseProgressiveMp4(parsedMp4, stdout, uint32(o.trackID), o.maxNrSamples)
+		err = parseProgressiveMp4(parsedMp4, stdout, o)
 		if err != nil {
 			return err
 		}
@@ -96,140 +129,253 @@ func run(args []string, stdout io.Writer) error {
 	}
 
 	// Fragmented file
-	err = parseFragmentedMp4(parsedMp4, stdout, uint32(o.trackID), o.maxNrSamples)
+	err = parseFragmentedMp4(parsedMp4, stdout, o)
 	if err != nil {
 		return err
 	}
 	return nil
 }
 
-func findTrack(moov *mp4.MoovBox, hdlrType string, trackID uint32) (*mp4.TrakBox, error) {
+// Errors when looking for a subtitle track
+var (
+	errNoMoov          = errors.New("no moov box")
+	errNoMatchingTrack = errors.New("no track with matching handler type or track ID")
+	errMissingStbl     = errors.New("missing sample table box")
+	errNoSampleEntry   = errors.New("no matching sample entry")
+	errTimingMismatch  = errors.New("inconsistent sample tables")
+)
+
+// findTrackByID - find track with trackID
+func findTrackByID(moov *mp4.MoovBox, trackID uint32) (*mp4.TrakBox, error) {
+	if moov == nil {
+		return nil, errNoMoov
+	}
 	for _, inTrak := range moov.Traks {
-		if trackID != 0 {
-			if inTrak.Tkhd.TrackID != trackID {
-				continue
-			}
+		if inTrak.Tkhd.TrackID == trackID {
 			return inTrak, nil
 		}
-		if inTrak.Mdia.Hdlr.HandlerType != hdlrType {
-			continue
-		}
-		return inTrak, nil
 	}
-	return nil, fmt.Errorf("no matching track found")
+	return nil, fmt.Errorf("%w: %d", errNoMatchingTrack, trackID)
+}
+
+// subtitleSampleTable - sample table of trak after checking that the sample counts are consistent
+func subtitleSampleTable(trak *mp4.TrakBox) (*mp4.StblBox, error) {
+	if trak.Mdia == nil || trak.Mdia.Minf == nil || trak.Mdia.Minf.Stbl == nil {
+		return nil, errMissingStbl
+	}
+	stbl := trak.Mdia.Minf.Stbl
+	if stbl.Stsd == nil || stbl.Stts == nil || stbl.Stsz == nil {
+		return nil, errMissingStbl
+	}
+	if err := stbl.CheckSampleCounts(); err != nil {
+		return nil, fmt.Errorf("%w: %v", errTimingMismatch, err)
+	}
+	return stbl, nil
 }
 
 type subtitleTrack struct {
-	variant string
-	trak    *mp4.TrakBox
+	variant  string
+	language string // ISO-639-2/T code from mdhd
+	trak     *mp4.TrakBox
 }
 
-func parseProgressiveMp4(f *mp4.File, w io.Writer, trackID uint32, maxNrSamples int) error {
-	subsTrak, err := findWvttTrack(f.Moov, w, trackID)
+// newSubtitleTrack - wvtt or tx3g track with handler type text, or stpp track with handler type subt.
+// The handler type is not checked if checkHandler is false.
+func newSubtitleTrack(trak *mp4.TrakBox, checkHandler bool) (*subtitleTrack, error) {
+	hdlrType := ""
+	if trak.Mdia != nil && trak.Mdia.Hdlr != nil {
+		hdlrType = trak.Mdia.Hdlr.HandlerType
+	}
+	if checkHandler && hdlrType != "text" && hdlrType != "subt" {
+		return nil, errNoMatchingTrack
+	}
+	stbl, err := subtitleSampleTable(trak)
 	if err != nil {
-		subsTrak, err = findStppTrack(f.Moov, w, trackID)
-		if err != nil {
-			return fmt.Errorf("no subtitle track found: %w", err)
+		return nil, err
+	}
+	st := &subtitleTrack{trak: trak}
+	switch {
+	case stbl.Stsd.Wvtt != nil && (!checkHandler || hdlrType == "text"):
+		st.variant = "wvtt"
+	case stbl.Stsd.Stpp != nil && (!checkHandler || hdlrType == "subt"):
+		st.variant = "stpp"
+	case stbl.Stsd.Tx3g != nil && (!checkHandler || hdlrType == "text"):
+		st.variant = "tx3g"
+	default:
+		return nil, fmt.Errorf("%w: no wvtt, stpp, or tx3g in track %d", errNoSampleEntry, trak.Tkhd.TrackID)
+	}
+	if trak.Mdia.Mdhd != nil {
+		st.language = trak.Mdia.Mdhd.GetLanguage()
+	}
+	return st, nil
+}
+
+// findAllSubtitleTracks - all wvtt, stpp, and tx3g tracks in moov in track order.
+// Tracks without a subtitle sample entry or with inconsistent sample tables are skipped.
+// If no track is found, the reason for skipping the last skipped track is returned.
+func findAllSubtitleTracks(moov *mp4.MoovBox) ([]*subtitleTrack, error) {
+	if moov == nil {
+		return nil, errNoMoov
+	}
+	var subsTraks []*subtitleTrack
+	notFoundErr := errNoMatchingTrack
+	for _, trak := range moov.Traks {
+		subsTrak, err := newSubtitleTrack(trak, true)
+		switch {
+		case errors.Is(err, errNoMatchingTrack):
+			continue
+		case err != nil:
+			notFoundErr = fmt.Errorf("track %d: %w", trak.Tkhd.TrackID, err)
+			continue
 		}
+		subsTraks = append(subsTraks, subsTrak)
+	}
+	if len(subsTraks) == 0 {
+		return nil, notFoundErr
+	}
+	return subsTraks, nil
+}
+
+// listSubtitleTracks - write track ID, variant, and language of all subtitle tracks to w
+func listSubtitleTracks(moov *mp4.MoovBox, w io.Writer) error {
+	subsTraks, err := findAllSubtitleTracks(moov)
+	if err != nil {
+		return fmt.Errorf("subtitle tracks: %w", err)
+	}
+	for _, st := range subsTraks {
+		fmt.Fprintf(w, "Track %d: %s, language %s\n", st.trak.Tkhd.TrackID, st.variant, st.language)
 	}
+	return nil
+}
+
+func parseProgressiveMp4(f *mp4.File, w io.Writer, o *options) error {
+	subsTrak, err := findSubtitleTrack(f.Moov, w, o)
+	if err != nil {
+		return err
+	}
+	ts := newTimeScaler(subsTrak.trak.Mdia.Mdhd.Timescale, o)
+	srt := &srtWriter{w: w}
 	stbl := subsTrak.trak.Mdia.Minf.Stbl
 	nrSamples := stbl.Stsz.SampleNumber
+	if nrSamples == 0 {
+		return nil
+	}
+	sampleRanges, err := subsTrak.trak.GetSampleRanges(1, nrSamples)
+	if err != nil {
+		return err
+	}
 	mdat := f.Mdat
+	if mdat == nil {
+		return fmt.Errorf("no mdat box")
+	}
 	mdatPayloadStart := mdat.PayloadAbsoluteOffset()
 	for sampleNr := 1; sampleNr <= int(nrSamples); sampleNr++ {
-		chunkNr, sampleNrAtChunkStart, err := stbl.Stsc.ChunkNrFromSampleNr(sampleNr)
-		if err != nil {
-			return err
-		}
-		var offset int64
-		if stbl.Stco != nil {
-			offset = int64(stbl.Stco.ChunkOffset[chunkNr-1])
-		} else if stbl.Co64 != nil {
-			offset = int64(stbl.Co64.ChunkOffset[chunkNr-1])
-		}
-		for sNr := sampleNrAtChunkStart; sNr < sampleNr; sNr++ {
-			offset += int64(stbl.Stsz.GetSampleSize(sNr))
-		}
-		size := stbl.Stsz.GetSampleSize(sampleNr)
 		decTime, dur := stbl.Stts.GetDecodeTime(uint32(sampleNr))
 		var cto int32 = 0
 		if stbl.Ctts != nil {
 			cto = stbl.Ctts.GetCompositionTimeOffset(uint32(sampleNr))
 		}
+		pts, dur := ts.interval(decTime+uint64(cto), dur)
+		if o.timingOnly {
+			printSampleTiming(w, sampleNr, pts, dur)
+			if sampleNr == o.maxNrSamples {
+				break
+			}
+			continue
+		}
 		// Next find sample bytes as slice in mdat
-		offsetInMdatData := uint64(offset) - mdatPayloadStart
-		sample := mdat.Data[offsetInMdatData : offsetInMdatData+uint64(size)]
-		switch subsTrak.variant {
-		case "wvtt":
-			err = printWvttSample(w, sample, sampleNr, decTime+uint64(cto), dur)
-		case "stpp":
-			err = printStppSample(w, sample, sampleNr, decTime+uint64(cto), dur)
+		sampleRange := sampleRanges[sampleNr-1]
+		offsetInMdatData := sampleRange.Offset - mdatPayloadStart
+		sample := mdat.Data[offsetInMdatData : offsetInMdatData+sampleRange.Size]
+		switch {
+		case o.srt && subsTrak.variant == "wvtt":
+			err = srt.writeWvttSample(sample, pts, dur)
+		case o.srt && subsTrak.variant == "tx3g":
+			err = srt.writeTx3gSample(sample, pts, dur)
+		case o.srt:
+			err = srt.writeStppSample(sample)
+		case subsTrak.variant == "wvtt":
+			err = printWvttSample(w, sample, sampleNr, pts, dur)
+		case subsTrak.variant == "stpp":
+			err = printStppSample(w, sample, sampleNr, pts, dur)
+		case subsTrak.variant == "tx3g":
+			err = printTx3gSample(w, sample, sampleNr, pts, dur)
 		}
 		if err != nil {
 			return err
 		}
-		if sampleNr == maxNrSamples {
+		if sampleNr == o.maxNrSamples {
 			break
 		}
 	}
 	return nil
 }
 
-func findWvttTrack(moov *mp4.MoovBox, w io.Writer, trackID uint32) (*subtitleTrack, error) {
-	subsTrak, err := findTrack(moov, "text", trackID)
+// findSubtitleTrack - find the wvtt, stpp, or tx3g track selected by track ID or language and print its header to w.
+// Without selection, the first subtitle track is used. No header is printed for SRT output.
+func findSubtitleTrack(moov *mp4.MoovBox, w io.Writer, o *options) (*subtitleTrack, error) {
+	subsTrak, err := selectSubtitleTrack(moov, o)
 	if err != nil {
-		return nil, err
+		return nil, fmt.Errorf("subtitle track: %w", err)
 	}
-
-	stbl := subsTrak.Mdia.Minf.Stbl
-	if stbl.Stsd.Wvtt == nil {
-		return nil, fmt.Errorf("no wvtt track found")
+	if o.srt || w == nil {
+		return subsTrak, nil
+	}
+	trak := subsTrak.trak
+	timescale := trak.Mdia.Mdhd.Timescale
+	if o.timescale != 0 {
+		timescale = uint32(o.timescale)
+	}
+	fmt.Fprintf(w, "Track %d, timescale = %d\n", trak.Tkhd.TrackID, timescale)
+	stsd := trak.Mdia.Minf.Stbl.Stsd
+	switch subsTrak.variant {
+	case "wvtt":
+		if stsd.Wvtt.VttC != nil {
+			err = stsd.Wvtt.VttC.Info(w, "", "  ", "  ")
+		}
+	case "stpp":
+		err = stsd.Stpp.Info(w, "", "  ", "  ")
+	case "tx3g":
+		err = stsd.Tx3g.Info(w, "", "  ", "  ")
 	}
-
-	fmt.Fprintf(w, "Track %d, timescale = %d\n", subsTrak.Tkhd.TrackID, subsTrak.Mdia.Mdhd.Timescale)
-	err = stbl.Stsd.Wvtt.VttC.Info(os.Stdout, "", "  ", "  ")
 	if err != nil {
 		return nil, err
 	}
-	return &subtitleTrack{
-		variant: "wvtt",
-		trak:    subsTrak,
-	}, nil
+	return subsTrak, nil
 }
 
-func findStppTrack(moov *mp4.MoovBox, w io.Writer, trackID uint32) (*subtitleTrack, error) {
-	subsTrak, err := findTrack(moov, "subt", trackID)
+// selectSubtitleTrack - subtitle track with o.trackID, or the first one with language o.lang if set
+func selectSubtitleTrack(moov *mp4.MoovBox, o *options) (*subtitleTrack, error) {
+	if o.trackID != 0 {
+		trak, err := findTrackByID(moov, uint32(o.trackID))
+		if err != nil {
+			return nil, err
+		}
+		return newSubtitleTrack(trak, false)
+	}
+	subsTraks, err := findAllSubtitleTracks(moov)
 	if err != nil {
 		return nil, err
 	}
-
-	stbl := subsTrak.Mdia.Minf.Stbl
-	if stbl.Stsd.Stpp == nil {
-		return nil, fmt.Errorf("no stpp track found")
+	if o.lang == "" {
+		return subsTraks[0], nil
 	}
-
-	fmt.Fprintf(w, "Track %d, timescale = %d\n", subsTrak.Tkhd.TrackID, subsTrak.Mdia.Mdhd.Timescale)
-	err = stbl.Stsd.Stpp.Info(w, "", "  ", "  ")
-	if err != nil {
-		return nil, err
+	for _, st := range subsTraks {
+		if st.language == o.lang {
+			return st, nil
+		}
 	}
-	return &subtitleTrack{
-		variant: "stpp",
-		trak:    subsTrak,
-	}, nil
+	return nil, fmt.Errorf("%w: language %s", errNoMatchingTrack, o.lang)
 }
 
-func parseFragmentedMp4(f *mp4.File, w io.Writer, trackID uint32, maxNrSamples int) error {
+func parseFragmentedMp4(f *mp4.File, w io.Writer, o *options) error {
 	var subsTrex *mp4.TrexBox
 	var subsTrak *subtitleTrack
 	var err error
 	if f.Init != nil { // Print vttC header and timescale if moov-box is present
-		subsTrak, err = findWvttTrack(f.Moov, w, trackID)
+		subsTrak, err = findSubtitleTrack(f.Moov, w, o)
 		if err != nil {
-			subsTrak, err = findStppTrack(f.Moov, w, trackID)
-			if err != nil {
-				return fmt.Errorf("no subtitle track found: %w", err)
-			}
+			return err
 		}
 		for _, trex := range f.Init.Moov.Mvex.Trexs {
 			if trex.TrackID == subsTrak.trak.Tkhd.TrackID {
@@ -273,12 +419,33 @@ func parseFragmentedMp4(f *mp4.File, w io.Writer, trackID uint32, maxNrSamples i
 			variant: variant,
 		}
 	}
+	var ts timeScaler
+	switch {
+	case subsTrak.trak != nil:
+		ts = newTimeScaler(subsTrak.trak.Mdia.Mdhd.Timescale, o)
+	case o.timescale != 0:
+		return fmt.Errorf("cannot change timescale without moov box")
+	case o.frameRate != "":
+		return fmt.Errorf("cannot snap to frame grid without moov box")
+	}
+	srt := &srtWriter{w: w}
 	for i, sample := range iSamples {
-		switch subsTrak.variant {
-		case "wvtt":
-			err = printWvttSample(w, sample.Data, i+1, sample.PresentationTime(), sample.Dur)
-		case "stpp":
-			err = printStppSample(w, sample.Data, i+1, sample.PresentationTime(), sample.Dur)
+		pts, dur := ts.interval(sample.PresentationTime(), sample.Dur)
+		switch {
+		case o.timingOnly:
+			printSampleTiming(w, i+1, pts, dur)
+		case o.srt && subsTrak.variant == "wvtt":
+			err = srt.writeWvttSample(sample.Data, pts, dur)
+		case o.srt && subsTrak.variant == "tx3g":
+			err = srt.writeTx3gSample(sample.Data, pts, dur)
+		case o.srt:
+			err = srt.writeStppSample(sample.Data)
+		case subsTrak.variant == "wvtt":
+			err = printWvttSample(w, sample.Data, i+1, pts, dur)
+		case subsTrak.variant == "stpp":
+			err = printStppSample(w, sample.Data, i+1, pts, dur)
+		case subsTrak.variant == "tx3g":
+			err = printTx3gSample(w, sample.Data, i+1, pts, dur)
 		default:
 			return fmt.Errorf("unknown subtitle track type")
 		}
@@ -286,15 +453,76 @@ func parseFragmentedMp4(f *mp4.File, w io.Writer, trackID uint32, maxNrSamples i
 		if err != nil {
 			return err
 		}
-		if i+1 == maxNrSamples {
+		if i+1 == o.maxNrSamples {
 			break
 		}
 	}
 	return nil
 }
 
-func printWvttSample(w io.Writer, sample []byte, nr int, pts uint64, dur uint32) error {
+// parseFrameRate - numerator and denominator of a frame rate like 25 or 30000/1001. Zero values for empty frameRate.
+func parseFrameRate(frameRate string) (num, den uint64, err error) {
+	if frameRate == "" {
+		return 0, 0, nil
+	}
+	numStr, denStr := frameRate, "1"
+	if i := strings.Index(frameRate, "/"); i >= 0 {
+		numStr, denStr = frameRate[:i], frameRate[i+1:]
+	}
+	num, err1 := strconv.ParseUint(numStr, 10, 32)
+	den, err2 := strconv.ParseUint(denStr, 10, 32)
+	if err1 != nil || err2 != nil || num == 0 || den == 0 {
+		return 0, 0, fmt.Errorf("bad frame rate %q", frameRate)
+	}
+	return num, den, nil
+}
+
+// timeScaler - rescale times from track timescale to output timescale with rounding.
+// With a frame rate, times are first rounded to the nearest frame start.
+// A zero timeScaler or outTimescale keeps the track timescale.
+type timeScaler struct {
+	trackTimescale uint32
+	outTimescale   uint32
+	frameRateNum   uint64
+	frameRateDen   uint64
+}
+
+func newTimeScaler(trackTimescale uint32, o *options) timeScaler {
+	return timeScaler{
+		trackTimescale: trackTimescale,
+		outTimescale:   uint32(o.timescale),
+		frameRateNum:   o.frameRateNum,
+		frameRateDen:   o.frameRateDen,
+	}
+}
+
+func (ts timeScaler) time(t uint64) uint64 {
+	trackTimescale, outTimescale := uint64(ts.trackTimescale), uint64(ts.outTimescale)
+	if outTimescale == 0 {
+		outTimescale = trackTimescale
+	}
+	if ts.frameRateNum != 0 && trackTimescale != 0 {
+		frameNr := (t*ts.frameRateNum + trackTimescale*ts.frameRateDen/2) / (trackTimescale * ts.frameRateDen)
+		return (frameNr*ts.frameRateDen*outTimescale + ts.frameRateNum/2) / ts.frameRateNum
+	}
+	if outTimescale == trackTimescale {
+		return t
+	}
+	return (t*outTimescale + trackTimescale/2) / trackTimescale
+}
+
+// interval - rescaled start and duration, where the duration is the difference of the rescaled end and start
+func (ts timeScaler) interval(start uint64, dur uint32) (uint64, uint32) {
+	outStart := ts.time(start)
+	return outStart, uint32(ts.time(start+uint64(dur)) - outStart)
+}
+
+func printSampleTiming(w io.Writer, nr int, pts uint64, dur uint32) {
 	fmt.Fprintf(w, "Sample %d, pts=%d, dur=%d\n", nr, pts, dur)
+}
+
+func printWvttSample(w io.Writer, sample []byte, nr int, pts uint64, dur uint32) error {
+	printSampleTiming(w, nr, pts, dur)
 	buf := bytes.NewBuffer(sample)
 	pos := 0
 	for {
@@ -318,7 +546,88 @@ func printWvttSample(w io.Writer, sample []byte, nr int, pts uint64, dur uint32)
 }
 
 func printStppSample(w io.Writer, sample []byte, nr int, pts uint64, dur uint32) error {
-	fmt.Fprintf(w, "Sample %d, pts=%d, dur=%d\n", nr, pts, dur)
+	printSampleTiming(w, nr, pts, dur)
 	_, err := w.Write(sample)
 	return err
 }
+
+// printTx3gSample - print timing and text of a tx3g sample. Styles are not printed.
+func printTx3gSample(w io.Writer, sample []byte, nr int, pts uint64, dur uint32) error {
+	printSampleTiming(w, nr, pts, dur)
+	tx3g, err := mp4.DecodeTx3gSample(sample)
+	if err != nil {
+		return err
+	}
+	_, err = fmt.Fprintf(w, "  text: %q\n", tx3g.Text)
+	return err
+}
+
+// srtWriter - write wvtt, stpp, or tx3g samples as numbered SRT (SubRip) cues with times in milliseconds
+type srtWriter struct {
+	w     io.Writer
+	cueNr int
+}
+
+// vttClassTag - WebVTT class start and end tags like <c.magenta> and </c>, which SRT does not support
+var vttClassTag = regexp.MustCompile(`</?c(\.[^>]*)?>`)
+
+// writeWvttSample - write one SRT cue with the text of all vttc boxes in sample.
+// An empty sample (vtte) results in a cue with timing, but no text.
+func (s *srtWriter) writeWvttSample(sample []byte, startMS uint64, durMS uint32) error {
+	var texts []string
+	buf := bytes.NewBuffer(sample)
+	pos := 0
+	for pos < len(sample) {
+		box, err := mp4.DecodeBox(uint64(pos), buf)
+		if err != nil {
+			return err
+		}
+		if vttc, ok := box.(*mp4.VttcBox); ok && vttc.Payl != nil {
+			texts = append(texts, vttClassTag.ReplaceAllString(vttc.Payl.CueText, ""))
+		}
+		pos += int(box.Size())
+	}
+	return s.writeCue(startMS, startMS+uint64(durMS), strings.Join(texts, "\n"))
+}
+
+// writeTx3gSample - write one SRT cue with the text of a tx3g sample.
+// An empty sample results in a cue with timing, but no text.
+func (s *srtWriter) writeTx3gSample(sample []byte, startMS uint64, durMS uint32) error {
+	tx3g, err := mp4.DecodeTx3gSample(sample)
+	if err != nil {
+		return err
+	}
+	return s.writeCue(startMS, startMS+uint64(durMS), tx3g.Text)
+}
+
+// writeStppSample - write one SRT cue per timed <p> element in the TTML document of sample.
+// The TTML times are used as they are, so they should be on the media timeline.
+func (s *srtWriter) writeStppSample(sample []byte) error {
+	cues, err := parseTTMLCues(sample)
+	if err != nil {
+		return err
+	}
+	for _, c := range cues {
+		err = s.writeCue(c.beginMS, c.endMS, c.text)
+		if err != nil {
+			return err
+		}
+	}
+	return nil
+}
+
+// writeCue - write numbered cue. Empty text gives a cue without text lines.
+func (s *srtWriter) writeCue(startMS, endMS uint64, text string) error {
+	s.cueNr++
+	cue := fmt.Sprintf("%d\n%s --> %s\n", s.cueNr, srtTime(startMS), srtTime(endMS))
+	if text != "" {
+		cue += text + "\n"
+	}
+	_, err := fmt.Fprint(s.w, cue+"\n")
+	return err
+}
+
+// srtTime - SRT time HH:MM:SS,mmm with comma as millisecond separator
+func srtTime(ms uint64) string {
+	return fmt.Sprintf("%02d:%02d:%02d,%03d", ms/3600000, (ms/60000)%60, (ms/1000)%60, ms%1000)
+}
@@ -2,10 +2,20 @@ package main
 
 import (
 	"bytes"
+	"errors"
+	"io"
+	"os"
+	"path"
+	"reflect"
+	"strings"
 	"testing"
+
+	"github.com/Eyevinn/mp4ff/mp4"
 )
 
 var wantedWvttShort = `Track 1, timescale = 1000
+  [vttC] size=14
+   - config: "WEBVTT"
 Sample 1, pts=0, dur=6640
 [vttc] size=52
   [sttg] size=18
@@ -120,6 +130,84 @@ var wantedStppSamples = `Sample 1, pts=0, dur=540000
 </tt>
 `
 
+var wantedWvttShortTiming = `Track 1, timescale = 1000
+  [vttC] size=14
+   - config: "WEBVTT"
+Sample 1, pts=0, dur=6640
+Sample 2, pts=6640, dur=320
+Sample 3, pts=6960, dur=3040
+Sample 4, pts=10000, dur=880
+Sample 5, pts=10880, dur=320
+Sample 6, pts=11200, dur=3160
+Sample 7, pts=14360, dur=320
+Sample 8, pts=14680, dur=5320
+`
+
+var wantedStppProgressiveTiming = wantedStppProgStart + `Sample 1, pts=0, dur=540000
+`
+
+var wantedWvttShortTiming90kHz = `Track 1, timescale = 90000
+  [vttC] size=14
+   - config: "WEBVTT"
+Sample 1, pts=0, dur=597600
+Sample 2, pts=597600, dur=28800
+Sample 3, pts=626400, dur=273600
+Sample 4, pts=900000, dur=79200
+Sample 5, pts=979200, dur=28800
+Sample 6, pts=1008000, dur=284400
+Sample 7, pts=1292400, dur=28800
+Sample 8, pts=1321200, dur=478800
+`
+
+var wantedStppProgressiveTimingMs = strings.Replace(wantedStppProgStart, "timescale = 90000", "timescale = 1000", 1) +
+	`Sample 1, pts=0, dur=6000
+`
+
+var wantedWvttShortTimingNTSC = `Track 1, timescale = 90000
+  [vttC] size=14
+   - config: "WEBVTT"
+Sample 1, pts=0, dur=597597
+Sample 2, pts=597597, dur=30030
+Sample 3, pts=627627, dur=273273
+Sample 4, pts=900900, dur=78078
+Sample 5, pts=978978, dur=30030
+Sample 6, pts=1009008, dur=282282
+Sample 7, pts=1291290, dur=30030
+Sample 8, pts=1321320, dur=477477
+`
+
+var wantedWvttShortSRT = `1
+00:00:00,000 --> 00:00:06,640
+...
+
+2
+00:00:06,640 --> 00:00:06,960
+
+3
+00:00:06,960 --> 00:00:10,000
+-Tout, tout, tout pourri,
+tout, tout, tout plaplat,
+
+`
+
+var wantedStppSRT = `1
+00:00:02,520 --> 00:00:04,120
+-Pourquoi ?
+
+2
+00:00:02,520 --> 00:00:04,120
+-J'ai...
+
+3
+00:00:04,520 --> 00:00:06,600
+J'ai un tas de trucs à faire.
+
+4
+00:00:04,520 --> 00:00:06,600
+-Non !
+
+`
+
 var wantedStppCombined = wantedStppCombinedStart + wantedStppSamples
 var wantedStppProgressive = wantedStppProgStart + wantedStppSamples
 
@@ -162,6 +250,90 @@ func TestSubsLister(t *testing.T) {
 			expectedErr: false,
 			wanted:      wantedStppProgressive,
 		},
+		{
+			desc:        "short wvtt timing",
+			args:        []string{appName, "-timing", "testdata/sample_short.ismt"},
+			expectedErr: false,
+			wanted:      wantedWvttShortTiming,
+		},
+		{
+			desc:        "stpp progressive timing",
+			args:        []string{appName, "-timing", "testdata/stpp_prog.mp4"},
+			expectedErr: false,
+			wanted:      wantedStppProgressiveTiming,
+		},
+		{
+			desc:        "short wvtt timing 90kHz",
+			args:        []string{appName, "-timing", "-timescale", "90000", "testdata/sample_short.ismt"},
+			expectedErr: false,
+			wanted:      wantedWvttShortTiming90kHz,
+		},
+		{
+			desc:        "stpp progressive timing ms",
+			args:        []string{appName, "-timing", "-timescale", "1000", "testdata/stpp_prog.mp4"},
+			expectedErr: false,
+			wanted:      wantedStppProgressiveTimingMs,
+		},
+		{
+			desc:        "short wvtt timing 29.97 fps",
+			args:        []string{appName, "-timing", "-timescale", "90000", "-framerate", "30000/1001", "testdata/sample_short.ismt"},
+			expectedErr: false,
+			wanted:      wantedWvttShortTimingNTSC,
+		},
+		{
+			desc:        "bad frame rate",
+			args:        []string{appName, "-framerate", "25/0", "testdata/sample_short.ismt"},
+			expectedErr: true,
+		},
+		{
+			desc:        "frame rate without moov",
+			args:        []string{appName, "-framerate", "25", "testdata/multi_vttc.mp4"},
+			expectedErr: true,
+		},
+		{
+			desc:        "timescale without moov",
+			args:        []string{appName, "-timescale", "1000", "testdata/multi_vttc.mp4"},
+			expectedErr: true,
+		},
+		{
+			desc:        "short wvtt srt",
+			args:        []string{appName, "-srt", "-m", "3", "testdata/sample_short.ismt"},
+			expectedErr: false,
+			wanted:      wantedWvttShortSRT,
+		},
+		{
+			desc:        "stpp srt",
+			args:        []string{appName, "-srt", "testdata/stpp_prog.mp4"},
+			expectedErr: false,
+			wanted:      wantedStppSRT,
+		},
+		{
+			desc:        "srt and timing",
+			args:        []string{appName, "-srt", "-timing", "testdata/sample_short.ismt"},
+			expectedErr: true,
+		},
+		{
+			desc:        "list tracks",
+			args:        []string{appName, "-list", "testdata/stpp_prog.mp4"},
+			expectedErr: false,
+			wanted:      "Track 1: stpp, language eng\n",
+		},
+		{
+			desc:        "list tracks without moov",
+			args:        []string{appName, "-list", "testdata/multi_vttc.mp4"},
+			expectedErr: true,
+		},
+		{
+			desc:        "stpp language",
+			args:        []string{appName, "-lang", "eng", "testdata/stpp_prog.mp4"},
+			expectedErr: false,
+			wanted:      wantedStppProgressive,
+		},
+		{
+			desc:        "missing language",
+			args:        []string{appName, "-lang", "swe", "testdata/stpp_prog.mp4"},
+			expectedErr: true,
+		},
 		{
 			desc:        "max nr samples",
 			args:        []string{appName, "-m", "1", "testdata/stpp_prog.mp4"},
@@ -192,3 +364,271 @@ func TestSubsLister(t *testing.T) {
 		})
 	}
 }
+
+func TestParseTTMLCues(t *testing.T) {
+	doc := `<tt xmlns="http://www.w3.org/ns/ttml"><body><div>
+  <p begin="00:01:02.5" end="63.25s">
+    <span>Fish &amp; chips</span><br/>
+    <span>&lt;hot&gt;</span> and
+    cold
+  </p>
+  <p begin="1h" end="3600100ms"></p>
+</div></body></tt>`
+	wanted := []ttmlCue{
+		{beginMS: 62500, endMS: 63250, text: "Fish & chips\n<hot> and cold"},
+		{beginMS: 3600000, endMS: 3600100, text: ""},
+	}
+	cues, err := parseTTMLCues([]byte(doc))
+	if err != nil {
+		t.Fatal(err)
+	}
+	if !reflect.DeepEqual(cues, wanted) {
+		t.Errorf("got cues %+v instead of %+v", cues, wanted)
+	}
+	_, err = parseTTMLCues([]byte(`<tt><p begin="10f" end="20f">x</p></tt>`))
+	if err == nil {
+		t.Error("expected error for frame based times")
+	}
+}
+
+func TestParseTTMLCuesInheritedTiming(t *testing.T) {
+	doc := `<tt xmlns="http://www.w3.org/ns/ttml"><body>
+<div begin="10s" end="20s">
+  <p>Untimed in timed div</p>
+  <p begin="1s" end="15s">Limited by div</p>
+</div>
+<div>
+  <p>No timing</p>
+  <p><span begin="5s" end="6s">Timed</span> <span begin="7s" dur="1s">spans</span></p>
+  <p begin="1s" dur="2s">Duration</p>
+</div>
+</body></tt>`
+	wanted := []ttmlCue{
+		{beginMS: 10000, endMS: 20000, text: "Untimed in timed div"},
+		{beginMS: 11000, endMS: 20000, text: "Limited by div"},
+		{beginMS: 5000, endMS: 8000, text: "Timed spans"},
+		{beginMS: 1000, endMS: 3000, text: "Duration"},
+	}
+	cues, err := parseTTMLCues([]byte(doc))
+	if err != nil {
+		t.Fatal(err)
+	}
+	if !reflect.DeepEqual(cues, wanted) {
+		t.Errorf("got cues %+v instead of %+v", cues, wanted)
+	}
+}
+
+func TestFindSubtitleTrackErrors(t *testing.T) {
+	testCases := []struct {
+		desc      string
+		trackID   int
+		modify    func(moov *mp4.MoovBox) *mp4.MoovBox
+		wantedErr error
+	}{
+		{desc: "ok", modify: func(moov *mp4.MoovBox) *mp4.MoovBox { return moov }},
+		{desc: "no moov", modify: func(moov *mp4.MoovBox) *mp4.MoovBox { return nil }, wantedErr: errNoMoov},
+		{desc: "no matching track", trackID: 17, modify: func(moov *mp4.MoovBox) *mp4.MoovBox { return moov },
+			wantedErr: errNoMatchingTrack},
+		{desc: "missing stbl", modify: func(moov *mp4.MoovBox) *mp4.MoovBox {
+			moov.Trak.Mdia.Minf.Stbl = nil
+			return moov
+		}, wantedErr: errMissingStbl},
+		{desc: "no stpp sample entry", modify: func(moov *mp4.MoovBox) *mp4.MoovBox {
+			moov.Trak.Mdia.Minf.Stbl.Stsd.Stpp = nil
+			return moov
+		}, wantedErr: errNoSampleEntry},
+		{desc: "timing mismatch", modify: func(moov *mp4.MoovBox) *mp4.MoovBox {
+			moov.Trak.Mdia.Minf.Stbl.Stts.SampleCount[0]++
+			return moov
+		}, wantedErr: errTimingMismatch},
+		{desc: "ctts mismatch", modify: func(moov *mp4.MoovBox) *mp4.MoovBox {
+			ctts := &mp4.CttsBox{}
+			_ = ctts.AddSampleCountsAndOffset([]uint32{2}, []int32{0})
+			moov.Trak.Mdia.Minf.Stbl.AddChild(ctts)
+			return moov
+		}, wantedErr: errTimingMismatch},
+	}
+	for _, tc := range testCases {
+		t.Run(tc.desc, func(t *testing.T) {
+			f, err := mp4.ReadMP4File("testdata/stpp_prog.mp4")
+			if err != nil {
+				t.Fatal(err)
+			}
+			moov := tc.modify(f.Moov)
+			subsTrak, err := findSubtitleTrack(moov, io.Discard, &options{trackID: tc.trackID})
+			if tc.wantedErr == nil {
+				if err != nil || subsTrak.variant != "stpp" {
+					t.Errorf("got error %v", err)
+				}
+				return
+			}
+			if !errors.Is(err, tc.wantedErr) {
+				t.Errorf("got error %v instead of %v", err, tc.wantedErr)
+			}
+		})
+	}
+}
+
+func TestSelectSubtitleTrack(t *testing.T) {
+	f, err := mp4.ReadMP4File("testdata/stpp_prog.mp4")
+	if err != nil {
+		t.Fatal(err)
+	}
+	f2, err := mp4.ReadMP4File("testdata/stpp_prog.mp4")
+	if err != nil {
+		t.Fatal(err)
+	}
+	sweTrak := f2.Moov.Trak
+	sweTrak.Tkhd.TrackID = 2
+	sweTrak.Mdia.Mdhd.SetLanguage("swe")
+	f.Moov.AddChild(sweTrak)
+	f3, err := mp4.ReadMP4File("testdata/stpp_prog.mp4")
+	if err != nil {
+		t.Fatal(err)
+	}
+	badTrak := f3.Moov.Trak // Inconsistent sample tables, so the track is skipped
+	badTrak.Tkhd.TrackID = 3
+	badTrak.Mdia.Minf.Stbl.Stts.SampleCount[0]++
+	f.Moov.AddChild(badTrak)
+
+	subsTraks, err := findAllSubtitleTracks(f.Moov)
+	if err != nil {
+		t.Fatal(err)
+	}
+	if len(subsTraks) != 2 || subsTraks[0].language != "eng" || subsTraks[1].language != "swe" {
+		t.Fatalf("got %d subtitle tracks", len(subsTraks))
+	}
+	testCases := []struct {
+		desc          string
+		o             options
+		wantedTrackID uint32
+	}{
+		{desc: "first", o: options{}, wantedTrackID: 1},
+		{desc: "language", o: options{lang: "swe"}, wantedTrackID: 2},
+		{desc: "track ID", o: options{trackID: 2}, wantedTrackID: 2},
+	}
+	for _, tc := range testCases {
+		t.Run(tc.desc, func(t *testing.T) {
+			subsTrak, err := selectSubtitleTrack(f.Moov, &tc.o)
+			if err != nil {
+				t.Fatal(err)
+			}
+			if subsTrak.trak.Tkhd.TrackID != tc.wantedTrackID {
+				t.Errorf("got track %d instead of %d", subsTrak.trak.Tkhd.TrackID, tc.wantedTrackID)
+			}
+		})
+	}
+	gotOut := bytes.Buffer{}
+	err = listSubtitleTracks(f.Moov, &gotOut)
+	if err != nil {
+		t.Fatal(err)
+	}
+	wanted := "Track 1: stpp, language eng\nTrack 2: stpp, language swe\n"
+	if gotOut.String() != wanted {
+		t.Errorf("got %q instead of %q", gotOut.String(), wanted)
+	}
+}
+
+var wantedTx3g = `Track 1, timescale = 1000
+  [tx3g] size=46
+   - dataReferenceIndex: 1
+   - displayFlags: 00000000
+   - justification: horizontal=0 vertical=0
+   - backgroundColor: 00000000
+   - defaultTextBox: top=0 left=0 bottom=0 right=0
+   - defaultStyle: fontID=0 faceStyleFlags=0 fontSize=0 textColor=00000000
+Sample 1, pts=0, dur=1000
+  text: "Hello"
+Sample 2, pts=1000, dur=500
+  text: ""
+`
+
+var wantedTx3gSRT = `1
+00:00:00,000 --> 00:00:01,000
+Hello
+
+2
+00:00:01,000 --> 00:00:01,500
+
+`
+
+// writeTx3gFile - write a progressive file with a tx3g track with one text sample and one empty sample
+func writeTx3gFile(t *testing.T, path string) {
+	t.Helper()
+	init := mp4.CreateEmptyInit()
+	init.AddEmptyTrack(1000, "text", "eng")
+	init.Moov.Trak.Mdia.Minf.Stbl.Stsd.AddChild(mp4.NewTx3gBox())
+	var samples []mp4.FullSample
+	for _, s := range []struct {
+		text string
+		dur  uint32
+	}{{"Hello", 1000}, {"", 500}} {
+		data := append([]byte{0, byte(len(s.text))}, s.text...)
+		samples = append(samples, mp4.FullSample{
+			Sample: mp4.NewSample(mp4.SyncSampleFlags, s.dur, uint32(len(data)), 0),
+			Data:   data,
+		})
+	}
+	f, err := mp4.CreateProgressiveFile(init, samples)
+	if err != nil {
+		t.Fatal(err)
+	}
+	ofh, err := os.Create(path)
+	if err != nil {
+		t.Fatal(err)
+	}
+	defer ofh.Close()
+	if err := f.Encode(ofh); err != nil {
+		t.Fatal(err)
+	}
+}
+
+func TestTx3gTrack(t *testing.T) {
+	inPath := path.Join(t.TempDir(), "tx3g.mp4")
+	writeTx3gFile(t, inPath)
+	testCases := []struct {
+		desc   string
+		args   []string
+		wanted string
+	}{
+		{desc: "samples", args: []string{appName, inPath}, wanted: wantedTx3g},
+		{desc: "srt", args: []string{appName, "-srt", inPath}, wanted: wantedTx3gSRT},
+		{desc: "list", args: []string{appName, "-list", inPath}, wanted: "Track 1: tx3g, language eng\n"},
+	}
+	for _, tc := range testCases {
+		gotOut := bytes.Buffer{}
+		if err := run(tc.args, &gotOut); err != nil {
+			t.Errorf("%s: %s", tc.desc, err)
+			continue
+		}
+		if gotOut.String() != tc.wanted {
+			t.Errorf("%s: got %q instead of %q", tc.desc, gotOut.String(), tc.wanted)
+		}
+	}
+}
+
+func TestTimeScaler(t *testing.T) {
+	// The same sample time 6.64s at 1000 Hz in different precisions
+	testCases := []struct {
+		desc      string
+		o         options
+		wantedPTS uint64
+		wantedDur uint32
+	}{
+		{desc: "track timescale", o: options{}, wantedPTS: 6640, wantedDur: 320},
+		{desc: "90kHz", o: options{timescale: 90000}, wantedPTS: 597600, wantedDur: 28800},
+		{desc: "milliseconds", o: options{timescale: 1000}, wantedPTS: 6640, wantedDur: 320},
+		{desc: "25 fps frame grid", o: options{frameRateNum: 25, frameRateDen: 1}, wantedPTS: 6640, wantedDur: 320},
+		{desc: "29.97 fps frame grid at 90kHz", o: options{timescale: 90000, frameRateNum: 30000, frameRateDen: 1001},
+			wantedPTS: 597597, wantedDur: 30030},
+		{desc: "29.97 fps frame grid in milliseconds", o: options{timescale: 1000, frameRateNum: 30000, frameRateDen: 1001},
+			wantedPTS: 6640, wantedDur: 334},
+	}
+	for _, tc := range testCases {
+		ts := newTimeScaler(1000, &tc.o)
+		pts, dur := ts.interval(6640, 320)
+		if pts != tc.wantedPTS || dur != tc.wantedDur {
+			t.Errorf("%s: got pts=%d dur=%d instead of pts=%d dur=%d", tc.desc, pts, dur, tc.wantedPTS, tc.wantedDur)
+		}
+	}
+}
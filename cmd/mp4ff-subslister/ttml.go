@@ -0,0 +1,201 @@
+package main
+
+import (
+	"bytes"
+	"encoding/xml"
+	"fmt"
+	"io"
+	"strconv"
+	"strings"
+)
+
+// ttmlCue - text and times in milliseconds of a TTML <p> element
+type ttmlCue struct {
+	beginMS, endMS uint64
+	text           string
+}
+
+// ttmlTiming - active interval in milliseconds of a TTML element. The end is only known if hasEnd is set.
+type ttmlTiming struct {
+	beginMS, endMS uint64
+	hasEnd         bool
+	explicit       bool // begin, end, or dur attribute on the element itself
+}
+
+// childTiming - timing of an element with attributes attrs inside an element with timing parent.
+// begin and end are relative to the begin of the parent, and the end is limited by the end of the parent.
+// Without begin, the element begins with its parent, and without end or dur, it ends with its parent.
+func childTiming(parent ttmlTiming, attrs []xml.Attr) (ttmlTiming, error) {
+	tm := ttmlTiming{beginMS: parent.beginMS, endMS: parent.endMS, hasEnd: parent.hasEnd}
+	var endMS, durMS uint64
+	var hasEnd, hasDur bool
+	for _, attr := range attrs {
+		var value uint64
+		var err error
+		switch attr.Name.Local {
+		case "begin", "end", "dur":
+			value, err = parseTTMLTime(attr.Value)
+			if err != nil {
+				return tm, err
+			}
+			tm.explicit = true
+		default:
+			continue
+		}
+		switch attr.Name.Local {
+		case "begin":
+			tm.beginMS = parent.beginMS + value
+		case "end":
+			endMS, hasEnd = value, true
+		case "dur":
+			durMS, hasDur = value, true
+		}
+	}
+	switch {
+	case hasEnd:
+		endMS += parent.beginMS
+	case hasDur:
+		endMS = tm.beginMS + durMS
+	default:
+		return tm, nil
+	}
+	if parent.hasEnd && endMS > parent.endMS {
+		endMS = parent.endMS
+	}
+	tm.endMS, tm.hasEnd = endMS, true
+	return tm, nil
+}
+
+// parseTTMLCues - extract cues from all <p> elements with resolved begin and end times in a TTML document.
+// Timing is inherited from ancestors like <body> and <div>. A <p> without a resolved end gets the
+// interval covered by its timed descendants like <span>, and is skipped if there are none.
+// Text in nested elements like <span> is included, <br/> gives a line break, and whitespace is collapsed.
+func parseTTMLCues(doc []byte) ([]ttmlCue, error) {
+	var cues []ttmlCue
+	var cue *ttmlCue
+	var pTiming ttmlTiming
+	var spans []ttmlTiming   // timed descendants of the current <p>
+	var timings []ttmlTiming // stack of timings of open elements
+	var text strings.Builder
+	d := xml.NewDecoder(bytes.NewReader(doc))
+	for {
+		tok, err := d.Token()
+		if err == io.EOF {
+			break
+		}
+		if err != nil {
+			return nil, err
+		}
+		switch t := tok.(type) {
+		case xml.StartElement:
+			var parent ttmlTiming
+			if n := len(timings); n > 0 {
+				parent = timings[n-1]
+			}
+			tm, err := childTiming(parent, t.Attr)
+			if err != nil {
+				return nil, err
+			}
+			timings = append(timings, tm)
+			switch {
+			case t.Name.Local == "p":
+				cue = &ttmlCue{}
+				pTiming = tm
+				spans = spans[:0]
+				text.Reset()
+			case t.Name.Local == "br" && cue != nil:
+				text.WriteString("\n")
+			case cue != nil && tm.explicit && tm.hasEnd:
+				spans = append(spans, tm)
+			}
+		case xml.CharData:
+			if cue != nil {
+				text.WriteString(strings.Map(lineBreakToSpace, string(t)))
+			}
+		case xml.EndElement:
+			if len(timings) > 0 {
+				timings = timings[:len(timings)-1]
+			}
+			if t.Name.Local == "p" && cue != nil {
+				if resolveCueTiming(cue, pTiming, spans) {
+					cue.text = collapseWhiteSpace(text.String())
+					cues = append(cues, *cue)
+				}
+				cue = nil
+			}
+		}
+	}
+	return cues, nil
+}
+
+// resolveCueTiming - set the times of cue from the timing of its <p> element, or from the interval covered
+// by the timed descendants spans if the end of the <p> is not resolved. Return false if there is no timing.
+func resolveCueTiming(cue *ttmlCue, p ttmlTiming, spans []ttmlTiming) bool {
+	if p.hasEnd {
+		cue.beginMS, cue.endMS = p.beginMS, p.endMS
+		return true
+	}
+	if len(spans) == 0 {
+		return false
+	}
+	cue.beginMS, cue.endMS = spans[0].beginMS, spans[0].endMS
+	for _, s := range spans[1:] {
+		if s.beginMS < cue.beginMS {
+			cue.beginMS = s.beginMS
+		}
+		if s.endMS > cue.endMS {
+			cue.endMS = s.endMS
+		}
+	}
+	return true
+}
+
+// lineBreakToSpace - line breaks in the document are white space, since only <br/> gives a line break
+func lineBreakToSpace(r rune) rune {
+	if r == '\n' || r == '\r' {
+		return ' '
+	}
+	return r
+}
+
+// collapseWhiteSpace - collapse white space within each line and remove empty lines
+func collapseWhiteSpace(text string) string {
+	var lines []string
+	for _, line := range strings.Split(text, "\n") {
+		if words := strings.Fields(line); len(words) > 0 {
+			lines = append(lines, strings.Join(words, " "))
+		}
+	}
+	return strings.Join(lines, "\n")
+}
+
+// parseTTMLTime - parse clock time HH:MM:SS[.fraction] or offset time like 2.5s or 100ms to milliseconds.
+// Frame and tick based times are not supported.
+func parseTTMLTime(value string) (uint64, error) {
+	if parts := strings.Split(value, ":"); len(parts) == 3 {
+		hours, err1 := strconv.ParseUint(parts[0], 10, 64)
+		minutes, err2 := strconv.ParseUint(parts[1], 10, 64)
+		seconds, err3 := strconv.ParseFloat(parts[2], 64)
+		if err1 != nil || err2 != nil || err3 != nil {
+			return 0, fmt.Errorf("bad TTML clock time %q", value)
+		}
+		return (hours*3600+minutes*60)*1000 + uint64(seconds*1000+0.5), nil
+	}
+	units := []struct {
+		metric string
+		ms     float64
+	}{
+		{"ms", 1}, {"h", 3600000}, {"m", 60000}, {"s", 1000},
+	}
+	for _, u := range units {
+		if !strings.HasSuffix(value, u.metric) {
+			continue
+		}
+		count, err := strconv.ParseFloat(strings.TrimSuffix(value, u.metric), 64)
+		if err != nil || count < 0 {
+			return 0, fmt.Errorf("bad TTML offset time %q", value)
+		}
+		return uint64(count*u.ms + 0.5), nil
+	}
+	return 0, fmt.Errorf("unsupported TTML time %q", value)
+}
@@ -0,0 +1,7 @@
+/*
+adts-extractor shows how to read the samples of a track in a progressive mp4 file
+using the sample tables in the moov box.
+The first audio track must be AAC-LC and its samples are written
+as raw AAC frames, each with an ADTS header.
+*/
+package main
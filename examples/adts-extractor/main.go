@@ -0,0 +1,165 @@
+package main
+
+import (
+	"bytes"
+	"errors"
+	"flag"
+	"fmt"
+	"io"
+	"os"
+
+	"github.com/Eyevinn/mp4ff/aac"
+	"github.com/Eyevinn/mp4ff/internal"
+	"github.com/Eyevinn/mp4ff/mp4"
+)
+
+const (
+	appName            = "adts-extractor"
+	adtsHeaderLength   = 7    // without CRC
+	maxADTSFrameLength = 8191 // 13-bit frame_length field
+)
+
+var usg = `%s shows how to extract the samples of the first audio track in a progressive mp4 file.
+The track must be AAC-LC, and each sample is written to the output file with an ADTS header.
+
+Usage of %s:
+`
+
+type options struct {
+	version bool
+}
+
+func parseOptions(fs *flag.FlagSet, args []string) (*options, error) {
+	fs.Usage = func() {
+		fmt.Fprintf(os.Stderr, usg, appName, appName)
+		fmt.Fprintf(os.Stderr, "\n%s [options] infile outfile\n\noptions:\n", appName)
+		fs.PrintDefaults()
+	}
+
+	opts := options{}
+
+	fs.BoolVar(&opts.version, "version", false, "Get mp4ff version")
+
+	err := fs.Parse(args[1:])
+	return &opts, err
+}
+
+func main() {
+	if err := run(os.Args, os.Stdout); err != nil {
+		fmt.Fprintf(os.Stderr, "error: %v\n", err)
+		os.Exit(1)
+	}
+}
+
+func run(args []string, stdout io.Writer) error {
+	fs := flag.NewFlagSet(appName, flag.ContinueOnError)
+	o, err := parseOptions(fs, args)
+
+	if err != nil {
+		if errors.Is(err, flag.ErrHelp) {
+			return nil
+		}
+		return err
+	}
+
+	if o.version {
+		fmt.Fprintf(stdout, "%s %s\n", appName, internal.GetVersion())
+		return nil
+	}
+
+	if len(fs.Args()) != 2 {
+		fs.Usage()
+		return fmt.Errorf("missing input or output file")
+	}
+
+	inFilePath := fs.Arg(0)
+	outFilePath := fs.Arg(1)
+
+	ifd, err := os.Open(inFilePath)
+	if err != nil {
+		return fmt.Errorf("error opening file: %w", err)
+	}
+	defer ifd.Close()
+	ofd, err := os.Create(outFilePath)
+	if err != nil {
+		return fmt.Errorf("error creating file: %w", err)
+	}
+	defer ofd.Close()
+
+	nrSamples, err := extractADTS(ifd, ofd)
+	if err != nil {
+		return err
+	}
+	fmt.Fprintf(stdout, "wrote %d ADTS frames\n", nrSamples)
+	return nil
+}
+
+// extractADTS - write the samples of the first audio track with ADTS headers to w
+func extractADTS(rs io.ReadSeeker, w io.Writer) (nrSamples int, err error) {
+	parsedMp4, err := mp4.DecodeFile(rs, mp4.WithDecodeMode(mp4.DecModeLazyMdat))
+	if err != nil {
+		return 0, fmt.Errorf("error parsing file: %w", err)
+	}
+	if parsedMp4.IsFragmented() {
+		return 0, fmt.Errorf("only progressive files are supported")
+	}
+	trak, err := findFirstAudioTrak(parsedMp4.Moov)
+	if err != nil {
+		return 0, err
+	}
+	asc, err := getAudioSpecificConfig(trak)
+	if err != nil {
+		return 0, err
+	}
+
+	// Sample sizes are taken from stsz, while CopySampleData finds the sample data via stsc and stco/co64
+	samples, err := trak.GetSampleData(1, trak.GetNrSamples())
+	if err != nil {
+		return 0, err
+	}
+	data := bytes.Buffer{}
+	err = parsedMp4.CopySampleData(&data, rs, trak, 1, trak.GetNrSamples(), nil)
+	if err != nil {
+		return 0, err
+	}
+	sampleData := data.Bytes()
+	var offset uint32
+	for i, s := range samples {
+		if s.Size+adtsHeaderLength > maxADTSFrameLength {
+			return 0, fmt.Errorf("sample %d: size %d too large for ADTS frame", i+1, s.Size)
+		}
+		hdr, err := aac.NewADTSHeader(asc.SamplingFrequency, asc.ChannelConfiguration, asc.ObjectType, uint16(s.Size))
+		if err != nil {
+			return 0, err
+		}
+		if _, err = w.Write(hdr.Encode()); err != nil {
+			return 0, err
+		}
+		if _, err = w.Write(sampleData[offset : offset+s.Size]); err != nil {
+			return 0, err
+		}
+		offset += s.Size
+	}
+	return len(samples), nil
+}
+
+func findFirstAudioTrak(moov *mp4.MoovBox) (*mp4.TrakBox, error) {
+	for _, trak := range moov.Traks {
+		if trak.Mdia.Hdlr.HandlerType == "soun" {
+			return trak, nil
+		}
+	}
+	return nil, fmt.Errorf("no audio track found")
+}
+
+func getAudioSpecificConfig(trak *mp4.TrakBox) (*aac.AudioSpecificConfig, error) {
+	mp4a := trak.Mdia.Minf.Stbl.Stsd.Mp4a
+	if mp4a == nil || mp4a.Esds == nil {
+		return nil, fmt.Errorf("audio track %d is not AAC", trak.Tkhd.TrackID)
+	}
+	dcd := mp4a.Esds.DecConfigDescriptor
+	if dcd == nil || dcd.DecSpecificInfo == nil {
+		return nil, fmt.Errorf("no AudioSpecificConfig in esds")
+	}
+	return aac.DecodeAudioSpecificConfig(bytes.NewReader(dcd.DecSpecificInfo.DecConfig))
+}
@@ -0,0 +1,80 @@
+package main
+
+import (
+	"bytes"
+	"os"
+	"path"
+	"testing"
+
+	"github.com/Eyevinn/mp4ff/aac"
+)
+
+func TestCommandLine(t *testing.T) {
+	tmpDir := t.TempDir()
+	testCases := []struct {
+		desc           string
+		args           []string
+		expectedErr    bool
+		wantedNrFrames int
+		wantedSize     int
+	}{
+		{desc: "help", args: []string{appName, "-h"}, expectedErr: false},
+		{desc: "version", args: []string{appName, "-version"}, expectedErr: false},
+		{desc: "no args", args: []string{appName}, expectedErr: true},
+		{desc: "unknown args", args: []string{appName, "-x"}, expectedErr: true},
+		{desc: "non-existing infile", args: []string{appName, "infile.mp4", path.Join(tmpDir, "out0.aac")}, expectedErr: true},
+		{
+			desc:        "fragmented file",
+			args:        []string{appName, "../../mp4/testdata/prog_8s_dec_dashinit.mp4", path.Join(tmpDir, "out1.aac")},
+			expectedErr: true,
+		},
+		{
+			desc:           "progressive file",
+			args:           []string{appName, "../../mp4/testdata/prog_8s.mp4", path.Join(tmpDir, "out2.aac")},
+			wantedNrFrames: 375,
+			wantedSize:     48752 + 375*7,
+		},
+	}
+
+	for _, c := range testCases {
+		t.Run(c.desc, func(t *testing.T) {
+			gotOut := bytes.Buffer{}
+			err := run(c.args, &gotOut)
+			if c.expectedErr {
+				if err == nil {
+					t.Error("expected error but got nil")
+				}
+				return
+			}
+			if err != nil {
+				t.Errorf("unexpected error: %s", err)
+				return
+			}
+			if c.wantedNrFrames == 0 {
+				return
+			}
+			data, err := os.ReadFile(c.args[len(c.args)-1])
+			if err != nil {
+				t.Fatal(err)
+			}
+			if len(data) != c.wantedSize {
+				t.Errorf("got %d bytes instead of %d", len(data), c.wantedSize)
+			}
+			nrFrames := 0
+			for pos := 0; pos < len(data); {
+				hdr, offset, err := aac.DecodeADTSHeader(bytes.NewReader(data[pos:]))
+				if err != nil {
+					t.Fatalf("frame %d: %s", nrFrames+1, err)
+				}
+				if offset != 0 || hdr.Frequency() != 48000 || hdr.ChannelConfig != 2 {
+					t.Fatalf("frame %d: unexpected ADTS header %+v at offset %d", nrFrames+1, hdr, offset)
+				}
+				pos += int(hdr.HeaderLength) + int(hdr.PayloadLength)
+				nrFrames++
+			}
+			if nrFrames != c.wantedNrFrames {
+				t.Errorf("got %d ADTS frames instead of %d", nrFrames, c.wantedNrFrames)
+			}
+		})
+	}
+}
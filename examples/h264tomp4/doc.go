@@ -0,0 +1,8 @@
+/*
+h264tomp4 shows how to remux an H.264 (AVC) byte stream in Annex B format into a progressive mp4 file.
+The NAL units are grouped into access units using AUD, parameter set, and SEI NAL units,
+as well as first_mb_in_slice in the slice headers.
+Each access unit becomes a sample with the duration given by the frame rate,
+and the composition time offsets are derived from the picture order count.
+*/
+package main
@@ -0,0 +1,235 @@
+package main
+
+import (
+	"encoding/binary"
+	"errors"
+	"flag"
+	"fmt"
+	"io"
+	"math"
+	"os"
+	"sort"
+	"strconv"
+	"strings"
+
+	"github.com/Eyevinn/mp4ff/avc"
+	"github.com/Eyevinn/mp4ff/internal"
+	"github.com/Eyevinn/mp4ff/mp4"
+)
+
+const (
+	appName = "h264tomp4"
+)
+
+var usg = `%s shows how to remux an H.264 (AVC) byte stream in Annex B format into a progressive mp4 file.
+Each access unit becomes a sample with duration given by the frame rate.
+Composition time offsets are derived from the picture order count of the pictures.
+If they need to be shifted to be non-negative, an edit list compensates for the shift.
+
+Usage of %s:
+`
+
+type options struct {
+	frameRate string
+	version   bool
+}
+
+func parseOptions(fs *flag.FlagSet, args []string) (*options, error) {
+	fs.Usage = func() {
+		fmt.Fprintf(os.Stderr, usg, appName, appName)
+		fmt.Fprintf(os.Stderr, "\n%s [options] infile outfile\n\noptions:\n", appName)
+		fs.PrintDefaults()
+	}
+
+	opts := options{}
+
+	fs.StringVar(&opts.frameRate, "r", "25", "Frame rate as integer or fraction like 30000/1001")
+	fs.BoolVar(&opts.version, "version", false, "Get mp4ff version")
+
+	err := fs.Parse(args[1:])
+	return &opts, err
+}
+
+func main() {
+	if err := run(os.Args, os.Stdout); err != nil {
+		fmt.Fprintf(os.Stderr, "error: %v\n", err)
+		os.Exit(1)
+	}
+}
+
+func run(args []string, stdout io.Writer) error {
+	fs := flag.NewFlagSet(appName, flag.ContinueOnError)
+	o, err := parseOptions(fs, args)
+
+	if err != nil {
+		if errors.Is(err, flag.ErrHelp) {
+			return nil
+		}
+		return err
+	}
+
+	if o.version {
+		fmt.Fprintf(stdout, "%s %s\n", appName, internal.GetVersion())
+		return nil
+	}
+
+	if len(fs.Args()) != 2 {
+		fs.Usage()
+		return fmt.Errorf("missing input or output file")
+	}
+
+	inFilePath := fs.Arg(0)
+	outFilePath := fs.Arg(1)
+
+	timescale, sampleDur, err := parseFrameRate(o.frameRate)
+	if err != nil {
+		return err
+	}
+	data, err := os.ReadFile(inFilePath)
+	if err != nil {
+		return fmt.Errorf("error reading file: %w", err)
+	}
+	f, err := remux(data, timescale, sampleDur)
+	if err != nil {
+		return err
+	}
+	ofd, err := os.Create(outFilePath)
+	if err != nil {
+		return fmt.Errorf("error creating file: %w", err)
+	}
+	defer ofd.Close()
+	err = f.Encode(ofd)
+	if err != nil {
+		return fmt.Errorf("error writing file: %w", err)
+	}
+	return nil
+}
+
+// parseFrameRate - get timescale and sample duration for a frame rate like 25 or 30000/1001.
+// The timescale is 90000 if the sample duration is then an integer.
+func parseFrameRate(frameRate string) (timescale, sampleDur uint32, err error) {
+	num, den := frameRate, "1"
+	if i := strings.Index(frameRate, "/"); i >= 0 {
+		num, den = frameRate[:i], frameRate[i+1:]
+	}
+	n, err1 := strconv.ParseUint(num, 10, 32)
+	d, err2 := strconv.ParseUint(den, 10, 32)
+	if err1 != nil || err2 != nil || n == 0 || d == 0 {
+		return 0, 0, fmt.Errorf("bad frame rate %q", frameRate)
+	}
+	if 90000*d%n == 0 {
+		return 90000, uint32(90000 * d / n), nil
+	}
+	return uint32(n), uint32(d), nil
+}
+
+// compositionTimeOffsets - offsets from presentation order given by the picture order count (POC).
+// The POC order restarts at IDR pictures and after pictures with MMCO 5.
+// All offsets are shifted by shift to be non-negative, so an edit list with media time shift
+// is needed to start the presentation at time 0.
+func compositionTimeOffsets(aus []avc.AccessUnit, spsMap map[uint32]*avc.SPS, sampleDur uint32) (offsets []int32, shift int32) {
+	pocCalc := avc.NewPOCCalculator()
+	pocs := make([]int, len(aus))
+	for i, au := range aus {
+		pocs[i] = pocCalc.Compute(au.SliceHeader, spsMap[au.SliceHeader.SeqParamID], au.IsIDR, au.NalRefIDC)
+	}
+	offsets = make([]int32, len(aus))
+	minOffset := int32(0)
+	groupStart := 0
+	for i := range aus {
+		if i+1 < len(aus) && !aus[i+1].IsIDR && !aus[i].SliceHeader.HasMMCO5 {
+			continue
+		}
+		group := make([]int, 0, i+1-groupStart) // decoding order index of pictures in presentation order
+		for j := groupStart; j <= i; j++ {
+			group = append(group, j)
+		}
+		sort.SliceStable(group, func(a, b int) bool { return pocs[group[a]] < pocs[group[b]] })
+		for rank, j := range group {
+			offsets[j] = int32(groupStart+rank-j) * int32(sampleDur)
+			if offsets[j] < minOffset {
+				minOffset = offsets[j]
+			}
+		}
+		groupStart = i + 1
+	}
+	for i := range offsets {
+		offsets[i] -= minOffset
+	}
+	return offsets, -minOffset
+}
+
+// addEditList - add an edit list to the track that skips the first mediaTime of the media timeline.
+// The segment duration of mediaDur is converted to the movie timescale.
+func addEditList(init *mp4.InitSegment, mediaDur uint64, mediaTime int64) {
+	trak := init.Moov.Trak
+	segmentDur := mediaDur * uint64(init.Moov.Mvhd.Timescale) / uint64(trak.Mdia.Mdhd.Timescale)
+	elst := &mp4.ElstBox{Entries: []mp4.ElstEntry{{SegmentDuration: segmentDur, MediaTime: mediaTime, MediaRateInteger: 1}}}
+	if segmentDur > math.MaxUint32 {
+		elst.Version = 1
+	}
+	edts := &mp4.EdtsBox{Elst: []*mp4.ElstBox{elst}}
+	edts.AddChild(elst)
+	// edts is placed directly after tkhd
+	children := []mp4.Box{trak.Tkhd, edts}
+	for _, child := range trak.Children {
+		if child != trak.Tkhd {
+			children = append(children, child)
+		}
+	}
+	trak.Children = children
+	trak.Edts = edts
+}
+
+// remux - make a progressive mp4 file with one sample per access unit of an Annex B byte stream
+func remux(data []byte, timescale, sampleDur uint32) (*mp4.File, error) {
+	nalus := avc.ExtractNalusFromByteStream(data)
+	if len(nalus) == 0 {
+		return nil, fmt.Errorf("no NAL units found. Not an Annex B byte stream?")
+	}
+	spsMap := make(map[uint32]*avc.SPS)
+	ppsMap := make(map[uint32]*avc.PPS)
+	aus, err := avc.SplitIntoAccessUnits(nalus, spsMap, ppsMap)
+	if err != nil {
+		return nil, err
+	}
+	spss, ppss := avc.GetParameterSetsFromByteStream(data)
+	if len(aus) == 0 || len(spss) == 0 || len(ppss) == 0 {
+		return nil, fmt.Errorf("no pictures or parameter sets found")
+	}
+
+	init := mp4.CreateEmptyInit()
+	init.AddEmptyTrack(timescale, "video", "und")
+	err = init.Moov.Trak.SetAVCDescriptor("avc1", spss, ppss, true)
+	if err != nil {
+		return nil, err
+	}
+
+	offsets, shift := compositionTimeOffsets(aus, spsMap, sampleDur)
+	if shift > 0 {
+		addEditList(init, uint64(len(aus))*uint64(sampleDur), int64(shift))
+	}
+	samples := make([]mp4.FullSample, 0, len(aus))
+	var decodeTime uint64
+	for i, au := range aus {
+		var sampleData []byte
+		naluLength := make([]byte, 4)
+		for _, idx := range au.NaluIndices {
+			nalu := nalus[idx]
+			binary.BigEndian.PutUint32(naluLength, uint32(len(nalu)))
+			sampleData = append(sampleData, naluLength...)
+			sampleData = append(sampleData, nalu...)
+		}
+		flags := mp4.NonSyncSampleFlags
+		if au.IsIDR {
+			flags = mp4.SyncSampleFlags
+		}
+		samples = append(samples, mp4.FullSample{
+			Sample:     mp4.NewSample(flags, sampleDur, uint32(len(sampleData)), offsets[i]),
+			DecodeTime: decodeTime,
+			Data:       sampleData,
+		})
+		decodeTime += uint64(sampleDur)
+	}
+	return mp4.CreateProgressiveFile(init, samples)
+}
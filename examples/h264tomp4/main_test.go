@@ -0,0 +1,144 @@
+package main
+
+import (
+	"bytes"
+	"os"
+	"path"
+	"testing"
+
+	"github.com/Eyevinn/mp4ff/avc"
+	"github.com/Eyevinn/mp4ff/mp4"
+	"github.com/go-test/deep"
+)
+
+func TestCommandLine(t *testing.T) {
+	tmpDir := t.TempDir()
+	inFile := "../../cmd/mp4ff-nallister/testdata/4pics.264"
+	testCases := []struct {
+		desc            string
+		args            []string
+		expectedErr     bool
+		wantedNrSamples uint32
+		wantedTimescale uint32
+		wantedDur       uint32
+	}{
+		{desc: "help", args: []string{appName, "-h"}, expectedErr: false},
+		{desc: "version", args: []string{appName, "-version"}, expectedErr: false},
+		{desc: "no args", args: []string{appName}, expectedErr: true},
+		{desc: "unknown args", args: []string{appName, "-x"}, expectedErr: true},
+		{desc: "non-existing infile", args: []string{appName, "infile.264", path.Join(tmpDir, "out0.mp4")}, expectedErr: true},
+		{desc: "not Annex B", args: []string{appName, "main.go", path.Join(tmpDir, "out1.mp4")}, expectedErr: true},
+		{
+			desc:        "bad frame rate",
+			args:        []string{appName, "-r", "25/0", inFile, path.Join(tmpDir, "out2.mp4")},
+			expectedErr: true,
+		},
+		{
+			desc:            "25 fps",
+			args:            []string{appName, inFile, path.Join(tmpDir, "out3.mp4")},
+			wantedNrSamples: 4,
+			wantedTimescale: 90000,
+			wantedDur:       3600,
+		},
+		{
+			desc:            "29.97 fps",
+			args:            []string{appName, "-r", "30000/1001", inFile, path.Join(tmpDir, "out4.mp4")},
+			wantedNrSamples: 4,
+			wantedTimescale: 30000,
+			wantedDur:       1001,
+		},
+	}
+
+	for _, c := range testCases {
+		t.Run(c.desc, func(t *testing.T) {
+			gotOut := bytes.Buffer{}
+			err := run(c.args, &gotOut)
+			if c.expectedErr {
+				if err == nil {
+					t.Error("expected error but got nil")
+				}
+				return
+			}
+			if err != nil {
+				t.Errorf("unexpected error: %s", err)
+				return
+			}
+			if c.wantedNrSamples == 0 {
+				return
+			}
+			f, err := mp4.ReadMP4File(c.args[len(c.args)-1])
+			if err != nil {
+				t.Fatal(err)
+			}
+			trak := f.Moov.Trak
+			if trak.Mdia.Mdhd.Timescale != c.wantedTimescale {
+				t.Errorf("got timescale %d instead of %d", trak.Mdia.Mdhd.Timescale, c.wantedTimescale)
+			}
+			stbl := trak.Mdia.Minf.Stbl
+			if stbl.Stsd.AvcX == nil || stbl.Stsd.AvcX.AvcC == nil {
+				t.Fatal("no avcC in output file")
+			}
+			if stbl.Ctts != nil {
+				t.Error("got ctts, but the pictures are in presentation order")
+			}
+			samples, err := trak.GetSampleData(1, trak.GetNrSamples())
+			if err != nil {
+				t.Fatal(err)
+			}
+			if len(samples) != int(c.wantedNrSamples) {
+				t.Fatalf("got %d samples instead of %d", len(samples), c.wantedNrSamples)
+			}
+			for i, s := range samples {
+				if s.Dur != c.wantedDur || s.IsSync() != (i == 0) {
+					t.Errorf("sample %d: got duration %d and sync %t", i+1, s.Dur, s.IsSync())
+				}
+			}
+		})
+	}
+}
+
+func TestCompositionTimeOffsets(t *testing.T) {
+	spsMap := map[uint32]*avc.SPS{0: {PicOrderCntType: 0, Log2MaxPicOrderCntLsbMinus4: 4}}
+	// I P B B in decoding order with POC 0 12 4 8, and a new IDR
+	aus := []avc.AccessUnit{
+		{IsIDR: true, NalRefIDC: 3, SliceHeader: &avc.SliceHeader{PicOrderCntLsb: 0}},
+		{NalRefIDC: 2, SliceHeader: &avc.SliceHeader{PicOrderCntLsb: 12}},
+		{NalRefIDC: 0, SliceHeader: &avc.SliceHeader{PicOrderCntLsb: 4}},
+		{NalRefIDC: 0, SliceHeader: &avc.SliceHeader{PicOrderCntLsb: 8}},
+		{IsIDR: true, NalRefIDC: 3, SliceHeader: &avc.SliceHeader{PicOrderCntLsb: 0}},
+	}
+	got, shift := compositionTimeOffsets(aus, spsMap, 10)
+	wanted := []int32{10, 30, 0, 0, 10}
+	if diff := deep.Equal(got, wanted); diff != nil {
+		t.Error(diff)
+	}
+	if shift != 10 {
+		t.Errorf("got shift %d instead of 10", shift)
+	}
+}
+
+func TestAddEditList(t *testing.T) {
+	init := mp4.CreateEmptyInit()
+	init.AddEmptyTrack(30000, "video", "und")
+	addEditList(init, 4*1001, 1001)
+	samples := make([]mp4.FullSample, 4)
+	for i := range samples {
+		samples[i] = mp4.FullSample{
+			Sample:     mp4.NewSample(mp4.SyncSampleFlags, 1001, 1, 1001),
+			DecodeTime: uint64(i * 1001),
+			Data:       []byte{byte(i)},
+		}
+	}
+	f, err := mp4.CreateProgressiveFile(init, samples)
+	if err != nil {
+		t.Fatal(err)
+	}
+	trak := f.Moov.Trak
+	if trak.Edts == nil || len(trak.Edts.Elst) != 1 || trak.Children[1] != trak.Edts {
+		t.Fatal("no edit list directly after tkhd")
+	}
+	wanted := []mp4.ElstEntry{{SegmentDuration: 12012, MediaTime: 1001, MediaRateInteger: 1}}
+	if diff := deep.Equal(trak.Edts.Elst[0].Entries, wanted); diff != nil {
+		t.Error(diff)
+	}
+}
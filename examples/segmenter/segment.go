@@ -273,10 +273,16 @@ func copyMediaData(trak *mp4.TrakBox, startSampleNr, endSampleNr uint32, rs io.R
 	var offset uint64
 	var startNr, endNr uint32
 	for i, chunk := range chunks {
-		if stbl.Co64 != nil {
-			offset = stbl.Co64.ChunkOffset[chunk.ChunkNr-1]
-		} else if stbl.Stco != nil {
-			offset = uint64(stbl.Stco.ChunkOffset[chunk.ChunkNr-1])
+		switch {
+		case stbl.Co64 != nil:
+			offset, err = stbl.Co64.GetOffset(int(chunk.ChunkNr))
+		case stbl.Stco != nil:
+			offset, err = stbl.Stco.GetOffset(int(chunk.ChunkNr))
+		default:
+			err = fmt.Errorf("neither stco nor co64 available")
+		}
+		if err != nil {
+			return err
 		}
 		startNr = chunk.StartSampleNr
 		endNr = startNr + chunk.NrSamples - 1
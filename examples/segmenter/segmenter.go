@@ -140,25 +140,19 @@ func (s *Segmenter) MakeMuxedInitSegment() (*mp4.InitSegment, error) {
 func (s *Segmenter) GetFullSamplesForInterval(mp4f *mp4.File, tr *Track, startSampleNr, endSampleNr uint32,
 	rs io.ReadSeeker) ([]mp4.FullSample, error) {
 	stbl := tr.inTrak.Mdia.Minf.Stbl
+	if endSampleNr < startSampleNr {
+		return nil, nil
+	}
+	sampleRanges, err := tr.inTrak.GetSampleRanges(startSampleNr, endSampleNr)
+	if err != nil {
+		return nil, err
+	}
 	samples := make([]mp4.FullSample, 0, endSampleNr-startSampleNr+1)
 	mdat := mp4f.Mdat
 	mdatPayloadStart := mdat.PayloadAbsoluteOffset()
 	for sampleNr := startSampleNr; sampleNr <= endSampleNr; sampleNr++ {
-		chunkNr, sampleNrAtChunkStart, err := stbl.Stsc.ChunkNrFromSampleNr(int(sampleNr))
-		if err != nil {
-			return nil, err
-		}
-		var offset int64
-		if stbl.Stco != nil {
-			offset = int64(stbl.Stco.ChunkOffset[chunkNr-1])
-		} else if stbl.Co64 != nil {
-			offset = int64(stbl.Co64.ChunkOffset[chunkNr-1])
-		}
-
-		for sNr := sampleNrAtChunkStart; sNr < int(sampleNr); sNr++ {
-			offset += int64(stbl.Stsz.GetSampleSize(sNr))
-		}
-		size := stbl.Stsz.GetSampleSize(int(sampleNr))
+		sampleRange := sampleRanges[sampleNr-startSampleNr]
+		offset, size := sampleRange.Offset, uint32(sampleRange.Size)
 		decTime, dur := stbl.Stts.GetDecodeTime(sampleNr)
 		var cto int32 = 0
 		if stbl.Ctts != nil {
@@ -167,7 +161,7 @@ func (s *Segmenter) GetFullSamplesForInterval(mp4f *mp4.File, tr *Track, startSa
 		var sampleData []byte
 		// Next find bytes as slice in mdat
 		if mdat.GetLazyDataSize() > 0 {
-			_, err := rs.Seek(offset, io.SeekStart)
+			_, err := rs.Seek(int64(offset), io.SeekStart)
 			if err != nil {
 				return nil, err
 			}
@@ -177,8 +171,8 @@ func (s *Segmenter) GetFullSamplesForInterval(mp4f *mp4.File, tr *Track, startSa
 				return nil, err
 			}
 		} else {
-			offsetInMdatData := uint64(offset) - mdatPayloadStart
-			sampleData = mdat.Data[offsetInMdatData : offsetInMdatData+uint64(size)]
+			offsetInMdatData := offset - mdatPayloadStart
+			sampleData = mdat.Data[offsetInMdatData : offsetInMdatData+sampleRange.Size]
 		}
 
 		//presTime := uint64(int64(decTime) + int64(cto))
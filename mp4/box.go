@@ -23,6 +23,11 @@ func init() {
 		"\xa9nam": DecodeGenericContainerBox,
 		"\xa9too": DecodeGenericContainerBox,
 		"\xa9cpy": DecodeGenericContainerBox,
+		"\xa9gen": DecodeGenericContainerBox,
+		"\xa9alb": DecodeGenericContainerBox,
+		"\xa9cmt": DecodeGenericContainerBox,
+		"\xa9day": DecodeGenericContainerBox,
+		"\xa9wrt": DecodeGenericContainerBox,
 		"ac-3":    DecodeAudioSampleEntry,
 		"ac-4":    DecodeAudioSampleEntry,
 		"alou":    DecodeLoudnessBaseBox,
@@ -34,10 +39,12 @@ func init() {
 		"btrt":    DecodeBtrt,
 		"cdat":    DecodeCdat,
 		"cdsc":    DecodeTrefType,
+		"chap":    DecodeTrefType,
 		"clap":    DecodeClap,
 		"co64":    DecodeCo64,
 		"CoLL":    DecodeCoLL,
 		"colr":    DecodeColr,
+		"covr":    DecodeGenericContainerBox,
 		"cslg":    DecodeCslg,
 		"ctim":    DecodeCtim,
 		"ctts":    DecodeCtts,
@@ -46,8 +53,11 @@ func init() {
 		"dec3":    DecodeDec3,
 		"desc":    DecodeGenericContainerBox,
 		"dinf":    DecodeDinf,
+		"disk":    DecodeGenericContainerBox,
 		"dpnd":    DecodeTrefType,
 		"dref":    DecodeDref,
+		"dvh1":    DecodeVisualSampleEntry,
+		"dvhe":    DecodeVisualSampleEntry,
 		"ec-3":    DecodeAudioSampleEntry,
 		"edts":    DecodeEdts,
 		"elng":    DecodeElng,
@@ -63,6 +73,7 @@ func init() {
 		"free":    DecodeFree,
 		"frma":    DecodeFrma,
 		"ftyp":    DecodeFtyp,
+		"gnre":    DecodeGenericContainerBox,
 		"hdlr":    DecodeHdlr,
 		"hev1":    DecodeVisualSampleEntry,
 		"hind":    DecodeTrefType,
@@ -73,6 +84,7 @@ func init() {
 		"ilst":    DecodeIlst,
 		"iods":    DecodeUnknown,
 		"ipir":    DecodeTrefType,
+		"keys":    DecodeKeys,
 		"kind":    DecodeKind,
 		"leva":    DecodeLeva,
 		"ludt":    DecodeLudt,
@@ -137,7 +149,9 @@ func init() {
 		"tref":    DecodeTref,
 		"trep":    DecodeTrep,
 		"trex":    DecodeTrex,
+		"trkn":    DecodeGenericContainerBox,
 		"trun":    DecodeTrun,
+		"tx3g":    DecodeTx3g,
 		"udta":    DecodeUdta,
 		"url ":    DecodeURLBox,
 		"uuid":    DecodeUUIDBox,
@@ -206,7 +220,15 @@ func DecodeHeader(r io.Reader) (BoxHeader, error) {
 		size = binary.BigEndian.Uint64(buf)
 		headerLen += largeSizeLen
 	case 0: // size 0 means to end of file
-		return BoxHeader{}, fmt.Errorf("Size 0, meaning to end of file, not supported")
+		rs, ok := r.(io.Seeker)
+		if !ok {
+			return BoxHeader{}, fmt.Errorf("Size 0, meaning to end of file, only supported for io.Seeker")
+		}
+		nrRemaining, err := remainingBytes(rs)
+		if err != nil {
+			return BoxHeader{}, err
+		}
+		size = uint64(headerLen) + nrRemaining
 	}
 	if uint64(headerLen) > size {
 		return BoxHeader{}, fmt.Errorf("box header size %d exceeds box size %d", headerLen, size)
@@ -214,6 +236,23 @@ func DecodeHeader(r io.Reader) (BoxHeader, error) {
 	return BoxHeader{string(buf[4:8]), size, headerLen}, nil
 }
 
+// remainingBytes - number of bytes from current position to end of rs. The position is not changed.
+func remainingBytes(rs io.Seeker) (uint64, error) {
+	pos, err := rs.Seek(0, io.SeekCurrent)
+	if err != nil {
+		return 0, err
+	}
+	end, err := rs.Seek(0, io.SeekEnd)
+	if err != nil {
+		return 0, err
+	}
+	_, err = rs.Seek(pos, io.SeekStart)
+	if err != nil {
+		return 0, err
+	}
+	return uint64(end - pos), nil
+}
+
 // EncodeHeader - encode a box header to a writer
 func EncodeHeader(b Box, w io.Writer) error {
 	boxType, boxSize := b.Type(), b.Size()
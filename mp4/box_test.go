@@ -2,7 +2,9 @@ package mp4_test
 
 import (
 	"bytes"
+	"encoding/binary"
 	"encoding/hex"
+	"math/rand"
 	"testing"
 
 	"github.com/Eyevinn/mp4ff/bits"
@@ -62,3 +64,52 @@ func TestFixed16and32(t *testing.T) {
 		t.Errorf("Fixed32(65536) should be 1.0, not %s", f32.String())
 	}
 }
+
+func TestDecodeBadBoxSizes(t *testing.T) {
+	testCases := []struct {
+		desc string
+		hex  string
+	}{
+		{desc: "size less than header", hex: "00000004667265650000"},
+		{desc: "large size less than header", hex: "00000001667265650000000000000008"},
+		{desc: "payload beyond data", hex: "0000001066726565000000"},
+		{desc: "short ftyp", hex: "0000000c6674797069736f36"},
+		{desc: "short styp", hex: "0000000c7374797069736f36"},
+		{desc: "child beyond parent", hex: "000000106d6f6f7600000010667265650000000000000000"},
+	}
+	for _, tc := range testCases {
+		t.Run(tc.desc, func(t *testing.T) {
+			data, err := hex.DecodeString(tc.hex)
+			if err != nil {
+				t.Fatal(err)
+			}
+			if _, err := mp4.DecodeBox(0, bytes.NewReader(data)); err == nil {
+				t.Error("DecodeBox: expected error")
+			}
+			if _, err := mp4.DecodeBoxSR(0, bits.NewFixedSliceReader(data)); err == nil {
+				t.Error("DecodeBoxSR: expected error")
+			}
+		})
+	}
+}
+
+// TestDecodeRandomBytes checks that random data, also after a plausible box header, gives errors but no panics.
+func TestDecodeRandomBytes(t *testing.T) {
+	boxTypes := []string{"ftyp", "styp", "free", "mdat", "moov", "moof", "xxxx"}
+	rng := rand.New(rand.NewSource(1))
+	for i := 0; i < 2000; i++ {
+		data := make([]byte, rng.Intn(256))
+		_, _ = rng.Read(data)
+		switch {
+		case i == 0:
+			data = []byte{0, 0, 0, 8, 'm', 'o', 'o', 'v'} // moov without trak
+		case len(data) >= 8 && i%2 == 0:
+			binary.BigEndian.PutUint32(data[0:4], uint32(rng.Intn(len(data)+16)))
+			copy(data[4:8], boxTypes[rng.Intn(len(boxTypes))])
+		}
+		_, _ = mp4.DecodeBox(0, bytes.NewReader(data))
+		_, _ = mp4.DecodeBoxSR(0, bits.NewFixedSliceReader(data))
+		_, _ = mp4.DecodeFile(bytes.NewReader(data))
+		_, _ = mp4.DecodeFileSR(bits.NewFixedSliceReader(data))
+	}
+}
@@ -0,0 +1,94 @@
+package mp4
+
+import (
+	"fmt"
+
+	"github.com/Eyevinn/mp4ff/bits"
+)
+
+// BoxIter - lazy iterator over a sequence of boxes in a slice reader
+//
+// Only the box headers are decoded. The payload of each box is a sub-slice of the
+// underlying data, and Children returns an iterator over the boxes inside it.
+// Iteration stops with an error at a header that cannot be read or a size that does not fit.
+//
+//	iter := mp4.NewBoxIter(bits.NewFixedSliceReader(data))
+//	for iter.Next() {
+//		hdr := iter.Header()
+//		...
+//	}
+//	if err := iter.Err(); err != nil {
+//		...
+//	}
+type BoxIter struct {
+	sr       bits.SliceReader
+	hdr      BoxHeader
+	startPos uint64
+	payload  []byte
+	err      error
+}
+
+// NewBoxIter - iterator over the boxes from the current position of sr to its end
+func NewBoxIter(sr bits.SliceReader) *BoxIter {
+	return &BoxIter{sr: sr}
+}
+
+// Next - advance to the next box. Returns false at the end of data or on error
+func (it *BoxIter) Next() bool {
+	if it.err != nil {
+		return false
+	}
+	it.payload = nil
+	nrLeft := it.sr.NrRemainingBytes()
+	if nrLeft == 0 {
+		return false
+	}
+	it.startPos = uint64(it.sr.GetPos())
+	if nrLeft < boxHeaderSize {
+		it.err = fmt.Errorf("%d bytes left at position %d, too few for a box header", nrLeft, it.startPos)
+		return false
+	}
+	hdr, err := DecodeHeaderSR(it.sr)
+	if err != nil {
+		it.err = fmt.Errorf("box header at position %d: %w", it.startPos, err)
+		return false
+	}
+	if hdr.Size-uint64(hdr.Hdrlen) > uint64(it.sr.NrRemainingBytes()) {
+		it.err = fmt.Errorf("%s box at position %d: size %d exceeds the %d bytes left",
+			hdr.Name, it.startPos, hdr.Size, nrLeft)
+		return false
+	}
+	it.hdr = hdr
+	it.payload = it.sr.ReadBytes(hdr.payloadLen())
+	if err := it.sr.AccError(); err != nil {
+		it.err = err
+		return false
+	}
+	return true
+}
+
+// Header - header of the current box
+func (it *BoxIter) Header() BoxHeader {
+	return it.hdr
+}
+
+// Range - start and end position of the current box including its header, relative to the start of the data
+func (it *BoxIter) Range() (start, end uint64) {
+	return it.startPos, it.startPos + it.hdr.Size
+}
+
+// Payload - box data after the header. It is a sub-slice of the data being iterated over
+func (it *BoxIter) Payload() []byte {
+	return it.payload
+}
+
+// Children - iterator over the child boxes of the current box.
+// Only valid for containers without version and flags or other data before the children.
+func (it *BoxIter) Children() *BoxIter {
+	return NewBoxIter(bits.NewFixedSliceReader(it.payload))
+}
+
+// Err - error that stopped the iteration, or nil if it ended at the end of data
+func (it *BoxIter) Err() error {
+	return it.err
+}
@@ -0,0 +1,84 @@
+package mp4_test
+
+import (
+	"os"
+	"testing"
+
+	"github.com/Eyevinn/mp4ff/bits"
+	"github.com/Eyevinn/mp4ff/mp4"
+	"github.com/go-test/deep"
+)
+
+func TestBoxIter(t *testing.T) {
+	data, err := os.ReadFile("testdata/prog_8s.mp4")
+	if err != nil {
+		t.Fatal(err)
+	}
+	var topBoxes, moovBoxes []string
+	iter := mp4.NewBoxIter(bits.NewFixedSliceReader(data))
+	for iter.Next() {
+		hdr := iter.Header()
+		topBoxes = append(topBoxes, hdr.Name)
+		start, end := iter.Range()
+		if end-start != hdr.Size || len(iter.Payload()) != int(hdr.Size)-hdr.Hdrlen {
+			t.Errorf("%s: range %d-%d and payload length %d for size %d", hdr.Name, start, end, len(iter.Payload()), hdr.Size)
+		}
+		if hdr.Name != "moov" {
+			continue
+		}
+		if start != 20 {
+			t.Errorf("moov starts at %d instead of 20", start)
+		}
+		children := iter.Children()
+		for children.Next() {
+			moovBoxes = append(moovBoxes, children.Header().Name)
+		}
+		if err := children.Err(); err != nil {
+			t.Error(err)
+		}
+	}
+	if err := iter.Err(); err != nil {
+		t.Error(err)
+	}
+	if diff := deep.Equal(topBoxes, []string{"ftyp", "moov", "mdat", "free"}); diff != nil {
+		t.Error(diff)
+	}
+	if diff := deep.Equal(moovBoxes, []string{"mvhd", "iods", "trak", "trak"}); diff != nil {
+		t.Error(diff)
+	}
+}
+
+func TestBoxIterLargeSizeAndBadSizes(t *testing.T) {
+	// free box with 64-bit size followed by a skip box
+	largeSize := []byte{0, 0, 0, 1, 'f', 'r', 'e', 'e', 0, 0, 0, 0, 0, 0, 0, 20, 1, 2, 3, 4,
+		0, 0, 0, 8, 's', 'k', 'i', 'p'}
+
+	testCases := []struct {
+		desc        string
+		data        []byte
+		wantedNames []string
+		wantedErr   bool
+	}{
+		{"large size", largeSize, []string{"free", "skip"}, false},
+		{"size beyond end", append([]byte{0, 0, 0, 8, 'f', 'r', 'e', 'e', 0, 0, 0, 9}, "skip"...), []string{"free"}, true},
+		{"size smaller than header", []byte{0, 0, 0, 4, 'f', 'r', 'e', 'e'}, nil, true},
+		{"truncated header", []byte{0, 0, 0, 8, 'f', 'r', 'e', 'e', 0, 0}, []string{"free"}, true},
+	}
+	for _, tc := range testCases {
+		var names []string
+		iter := mp4.NewBoxIter(bits.NewFixedSliceReader(tc.data))
+		for iter.Next() {
+			names = append(names, iter.Header().Name)
+		}
+		if diff := deep.Equal(names, tc.wantedNames); diff != nil {
+			t.Errorf("%s: %v", tc.desc, diff)
+		}
+		if gotErr := iter.Err() != nil; gotErr != tc.wantedErr {
+			t.Errorf("%s: got error %v", tc.desc, iter.Err())
+		}
+	}
+	iter := mp4.NewBoxIter(bits.NewFixedSliceReader(largeSize))
+	if !iter.Next() || iter.Header().Hdrlen != 16 || string(iter.Payload()) != "\x01\x02\x03\x04" {
+		t.Errorf("bad large size box %+v with payload %x", iter.Header(), iter.Payload())
+	}
+}
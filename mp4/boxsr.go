@@ -11,9 +11,14 @@ var decodersSR map[string]BoxDecoderSR
 func init() {
 	decodersSR = map[string]BoxDecoderSR{
 		"\xa9ART": DecodeGenericContainerBoxSR,
+		"\xa9alb": DecodeGenericContainerBoxSR,
+		"\xa9cmt": DecodeGenericContainerBoxSR,
 		"\xa9cpy": DecodeGenericContainerBoxSR,
+		"\xa9day": DecodeGenericContainerBoxSR,
+		"\xa9gen": DecodeGenericContainerBoxSR,
 		"\xa9nam": DecodeGenericContainerBoxSR,
 		"\xa9too": DecodeGenericContainerBoxSR,
+		"\xa9wrt": DecodeGenericContainerBoxSR,
 		"ac-3":    DecodeAudioSampleEntrySR,
 		"ac-4":    DecodeAudioSampleEntrySR,
 		"alou":    DecodeLoudnessBaseBoxSR,
@@ -25,10 +30,12 @@ func init() {
 		"btrt":    DecodeBtrtSR,
 		"cdat":    DecodeCdatSR,
 		"cdsc":    DecodeTrefTypeSR,
+		"chap":    DecodeTrefTypeSR,
 		"clap":    DecodeClapSR,
 		"co64":    DecodeCo64SR,
 		"CoLL":    DecodeCoLLSR,
 		"colr":    DecodeColrSR,
+		"covr":    DecodeGenericContainerBoxSR,
 		"cslg":    DecodeCslgSR,
 		"ctim":    DecodeCtimSR,
 		"ctts":    DecodeCttsSR,
@@ -37,8 +44,11 @@ func init() {
 		"dec3":    DecodeDec3SR,
 		"desc":    DecodeGenericContainerBoxSR,
 		"dinf":    DecodeDinfSR,
+		"disk":    DecodeGenericContainerBoxSR,
 		"dpnd":    DecodeTrefTypeSR,
 		"dref":    DecodeDrefSR,
+		"dvh1":    DecodeVisualSampleEntrySR,
+		"dvhe":    DecodeVisualSampleEntrySR,
 		"ec-3":    DecodeAudioSampleEntrySR,
 		"edts":    DecodeEdtsSR,
 		"elng":    DecodeElngSR,
@@ -54,6 +64,7 @@ func init() {
 		"free":    DecodeFreeSR,
 		"frma":    DecodeFrmaSR,
 		"ftyp":    DecodeFtypSR,
+		"gnre":    DecodeGenericContainerBoxSR,
 		"hdlr":    DecodeHdlrSR,
 		"hev1":    DecodeVisualSampleEntrySR,
 		"hind":    DecodeTrefTypeSR,
@@ -64,6 +75,7 @@ func init() {
 		"ilst":    DecodeIlstSR,
 		"iods":    DecodeUnknownSR,
 		"ipir":    DecodeTrefTypeSR,
+		"keys":    DecodeKeysSR,
 		"kind":    DecodeKindSR,
 		"leva":    DecodeLevaSR,
 		"ludt":    DecodeLudtSR,
@@ -128,7 +140,9 @@ func init() {
 		"tref":    DecodeTrefSR,
 		"trep":    DecodeTrepSR,
 		"trex":    DecodeTrexSR,
+		"trkn":    DecodeGenericContainerBoxSR,
 		"trun":    DecodeTrunSR,
+		"tx3g":    DecodeTx3gSR,
 		"udta":    DecodeUdtaSR,
 		"url ":    DecodeURLBoxSR,
 		"uuid":    DecodeUUIDBoxSR,
@@ -197,7 +211,7 @@ func DecodeHeaderSR(sr bits.SliceReader) (BoxHeader, error) {
 		size = sr.ReadUint64()
 		headerLen += largeSizeLen
 	case 0: // size 0 means to end of file
-		return BoxHeader{}, fmt.Errorf("Size 0, meaning to end of file, not supported")
+		size = uint64(headerLen + sr.NrRemainingBytes())
 	}
 	if uint64(headerLen) > size {
 		return BoxHeader{}, fmt.Errorf("box header size %d exceeds box size %d", headerLen, size)
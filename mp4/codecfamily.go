@@ -0,0 +1,64 @@
+package mp4
+
+// CodecFamily - video codec family of a sample entry
+type CodecFamily int
+
+// Video codec families
+const (
+	CodecFamilyUnknown CodecFamily = iota
+	CodecFamilyAvc
+	CodecFamilyHevc
+	CodecFamilyDolbyVision
+	CodecFamilyAv1
+)
+
+// String - name of codec family
+func (c CodecFamily) String() string {
+	switch c {
+	case CodecFamilyAvc:
+		return "AVC"
+	case CodecFamilyHevc:
+		return "HEVC"
+	case CodecFamilyDolbyVision:
+		return "DolbyVision"
+	case CodecFamilyAv1:
+		return "AV1"
+	default:
+		return "Unknown"
+	}
+}
+
+// GetCodecFamily - codec family of a visual sample entry given its type (or original format if encrypted).
+// Dolby Vision is recognized by the dvh1 and dvhe types, and by a dvcC or dvvC box in
+// an hvc1, hev1, avc1, or avc3 sample entry.
+// supported is true if the decoder configuration box (avcC, hvcC, or av1C) for the family is present.
+func GetCodecFamily(vse *VisualSampleEntryBox) (family CodecFamily, supported bool) {
+	format := vse.Type()
+	if vse.Sinf != nil && vse.Sinf.Frma != nil {
+		format = vse.Sinf.Frma.DataFormat
+	}
+	hasDvConfig := false
+	for _, child := range vse.Children {
+		if child.Type() == "dvcC" || child.Type() == "dvvC" {
+			hasDvConfig = true
+		}
+	}
+	switch format {
+	case "avc1", "avc3":
+		if hasDvConfig {
+			return CodecFamilyDolbyVision, vse.AvcC != nil
+		}
+		return CodecFamilyAvc, vse.AvcC != nil
+	case "hvc1", "hev1":
+		if hasDvConfig {
+			return CodecFamilyDolbyVision, vse.HvcC != nil
+		}
+		return CodecFamilyHevc, vse.HvcC != nil
+	case "dvh1", "dvhe":
+		return CodecFamilyDolbyVision, vse.HvcC != nil
+	case "av01":
+		return CodecFamilyAv1, vse.Av1C != nil
+	default:
+		return CodecFamilyUnknown, false
+	}
+}
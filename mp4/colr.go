@@ -143,3 +143,55 @@ func (c *ColrBox) Info(w io.Writer, specificBoxLevels, indent, indentStep string
 	}
 	return bd.err
 }
+
+// TransferCharacteristicsName returns the name of a transfer characteristics code point
+// as defined in ITU-T H.273. The names follow ffmpeg. Empty string if unknown or unspecified.
+// HDR transfer functions are "smpte2084" (PQ) and "arib-std-b67" (HLG).
+func TransferCharacteristicsName(tc uint16) string {
+	switch tc {
+	case 1:
+		return "bt709"
+	case 4:
+		return "bt470m"
+	case 5:
+		return "bt470bg"
+	case 6:
+		return "smpte170m"
+	case 7:
+		return "smpte240m"
+	case 8:
+		return "linear"
+	case 9:
+		return "log100"
+	case 10:
+		return "log316"
+	case 11:
+		return "iec61966-2-4"
+	case 12:
+		return "bt1361e"
+	case 13:
+		return "iec61966-2-1"
+	case 14:
+		return "bt2020-10"
+	case 15:
+		return "bt2020-12"
+	case 16:
+		return "smpte2084"
+	case 17:
+		return "smpte428"
+	case 18:
+		return "arib-std-b67"
+	default:
+		return ""
+	}
+}
+
+// TransferCharacteristicsName returns the name of the transfer characteristics. Empty if not known.
+func (c *ColrBox) TransferCharacteristicsName() string {
+	switch c.ColorType {
+	case ColorTypeOnScreenColors, QuickTimeColorParameters:
+		return TransferCharacteristicsName(c.TransferCharacteristics)
+	default:
+		return ""
+	}
+}
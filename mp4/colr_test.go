@@ -83,3 +83,22 @@ func TestColrInfo(t *testing.T) {
 		}
 	}
 }
+
+func TestColrTransferCharacteristicsName(t *testing.T) {
+	cases := []struct {
+		colr mp4.ColrBox
+		want string
+	}{
+		{mp4.ColrBox{ColorType: mp4.ColorTypeOnScreenColors, TransferCharacteristics: 1}, "bt709"},
+		{mp4.ColrBox{ColorType: mp4.ColorTypeOnScreenColors, TransferCharacteristics: 16}, "smpte2084"},
+		{mp4.ColrBox{ColorType: mp4.QuickTimeColorParameters, TransferCharacteristics: 18}, "arib-std-b67"},
+		{mp4.ColrBox{ColorType: mp4.ColorTypeOnScreenColors, TransferCharacteristics: 2}, ""},
+		{mp4.ColrBox{ColorType: mp4.ColorTypeRestrictedICCProfile, ICCProfile: []byte{1}}, ""},
+	}
+	for _, c := range cases {
+		got := c.colr.TransferCharacteristicsName()
+		if got != c.want {
+			t.Errorf("got %q instead of %q for %+v", got, c.want, c.colr)
+		}
+	}
+}
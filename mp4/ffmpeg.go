@@ -13,10 +13,28 @@ type CTooBox struct {
 }
 
 // DataBox - data box used by ffmpeg for providing information.
+// DataType is the well-known type of Data, e.g. DataTypeUTF8 for text or DataTypeJPEG for cover art.
+// The zero value DataTypeUTF8 means UTF-8 text, as before DataType was added.
 type DataBox struct {
-	Data []byte
+	DataType uint32
+	Data     []byte
 }
 
+// NewDataBox - new DataBox with well-known type dataType
+func NewDataBox(dataType uint32, data []byte) *DataBox {
+	return &DataBox{DataType: dataType, Data: data}
+}
+
+// Well-known data types of DataBox. Except for DataTypeUTF8 and DataTypeBinary,
+// the values are the type codes of the QuickTime File Format.
+const (
+	DataTypeUTF8   uint32 = 0          // written as type code 1
+	DataTypeBinary uint32 = 0xffffffff // written as type code 0
+	DataTypeJPEG   uint32 = 13
+	DataTypePNG    uint32 = 14
+	DataTypeBMP    uint32 = 27
+)
+
 // DecodeData - decode Data (from mov_write_string_data_tag in movenc.c in ffmpeg)
 func DecodeData(hdr BoxHeader, startPos uint64, r io.Reader) (Box, error) {
 	data, err := readBoxBody(r, hdr)
@@ -29,9 +47,27 @@ func DecodeData(hdr BoxHeader, startPos uint64, r io.Reader) (Box, error) {
 
 // DecodeDataSR - decode Data (from mov_write_string_data_tag in movenc.c in ffmpeg)
 func DecodeDataSR(hdr BoxHeader, startPos uint64, sr bits.SliceReader) (Box, error) {
-	_ = sr.ReadUint32() // Should be 1
-	_ = sr.ReadUint32() // Should be 0
-	return &DataBox{sr.ReadBytes(hdr.payloadLen() - 8)}, sr.AccError()
+	dataType := sr.ReadUint32() & 0x00ffffff // Top byte is type indicator set, always 0
+	_ = sr.ReadUint32()                      // Locale, should be 0
+	switch dataType {
+	case 0:
+		dataType = DataTypeBinary
+	case 1:
+		dataType = DataTypeUTF8
+	}
+	return NewDataBox(dataType, sr.ReadBytes(hdr.payloadLen()-8)), sr.AccError()
+}
+
+// typeCode - QuickTime type code written for DataType
+func (b *DataBox) typeCode() uint32 {
+	switch b.DataType {
+	case DataTypeUTF8:
+		return 1
+	case DataTypeBinary:
+		return 0
+	default:
+		return b.DataType
+	}
 }
 
 // Type - box type
@@ -61,8 +97,8 @@ func (b *DataBox) EncodeSW(sw bits.SliceWriter) error {
 	if err != nil {
 		return err
 	}
-	sw.WriteUint32(0x00000001)
-	sw.WriteUint32(0x00000000)
+	sw.WriteUint32(b.typeCode())
+	sw.WriteUint32(0x00000000) // Locale
 	sw.WriteBytes(b.Data)
 	return sw.AccError()
 }
@@ -70,6 +106,10 @@ func (b *DataBox) EncodeSW(sw bits.SliceWriter) error {
 // Info - box-specific Info
 func (b *DataBox) Info(w io.Writer, specificBoxLevels, indent, indentStep string) error {
 	bd := newInfoDumper(w, indent, b, -1, 0)
+	if b.DataType != DataTypeUTF8 {
+		bd.write(" - dataType: %d, %d bytes", b.typeCode(), len(b.Data))
+		return bd.err
+	}
 	bd.write(" - data: %s", string(b.Data))
 	return bd.err
 }
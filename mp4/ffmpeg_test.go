@@ -2,6 +2,7 @@ package mp4_test
 
 import (
 	"bytes"
+	"encoding/binary"
 	"encoding/hex"
 	"testing"
 
@@ -37,3 +38,40 @@ func TestEncodeData(t *testing.T) {
 	db := &mp4.DataBox{Data: data}
 	boxDiffAfterEncodeAndDecode(t, db)
 }
+
+func TestDataBoxDefaultType(t *testing.T) {
+	testCases := []struct {
+		desc           string
+		db             *mp4.DataBox
+		wantedTypeCode uint32
+		wantedInfo     string
+	}{
+		{desc: "literal", db: &mp4.DataBox{Data: []byte("x")}, wantedTypeCode: 1,
+			wantedInfo: "[data] size=17\n - data: x\n"},
+		{desc: "binary", db: mp4.NewDataBox(mp4.DataTypeBinary, []byte("x")), wantedTypeCode: 0,
+			wantedInfo: "[data] size=17\n - dataType: 0, 1 bytes\n"},
+		{desc: "binary literal", db: &mp4.DataBox{DataType: mp4.DataTypeBinary, Data: []byte("x")}, wantedTypeCode: 0,
+			wantedInfo: "[data] size=17\n - dataType: 0, 1 bytes\n"},
+		{desc: "jpeg", db: &mp4.DataBox{DataType: mp4.DataTypeJPEG, Data: []byte("x")}, wantedTypeCode: 13,
+			wantedInfo: "[data] size=17\n - dataType: 13, 1 bytes\n"},
+	}
+	for _, tc := range testCases {
+		t.Run(tc.desc, func(t *testing.T) {
+			boxDiffAfterEncodeAndDecode(t, tc.db)
+			buf := bytes.Buffer{}
+			if err := tc.db.Encode(&buf); err != nil {
+				t.Fatal(err)
+			}
+			if got := binary.BigEndian.Uint32(buf.Bytes()[8:12]); got != tc.wantedTypeCode {
+				t.Errorf("got type code %d instead of %d", got, tc.wantedTypeCode)
+			}
+			info := bytes.Buffer{}
+			if err := tc.db.Info(&info, "", "", "  "); err != nil {
+				t.Fatal(err)
+			}
+			if info.String() != tc.wantedInfo {
+				t.Errorf("got info %q instead of %q", info.String(), tc.wantedInfo)
+			}
+		})
+	}
+}
@@ -259,7 +259,7 @@ func (f *File) AddChild(child Box, boxStartPos uint64) {
 		f.Ftyp = box
 	case *MoovBox:
 		f.Moov = box
-		if len(f.Moov.Trak.Mdia.Minf.Stbl.Stts.SampleCount) == 0 {
+		if !firstTrakHasSamples(box) {
 			f.isFragmented = true
 			f.Init = NewMP4Init()
 			f.Init.AddChild(f.Ftyp)
@@ -559,6 +559,16 @@ func (f *File) LastSegment() *MediaSegment {
 	return f.Segments[len(f.Segments)-1]
 }
 
+// firstTrakHasSamples - first trak has stts entries, which is not the case in fragmented files.
+func firstTrakHasSamples(moov *MoovBox) bool {
+	trak := moov.Trak
+	if trak == nil || trak.Mdia == nil || trak.Mdia.Minf == nil || trak.Mdia.Minf.Stbl == nil {
+		return false
+	}
+	stts := trak.Mdia.Minf.Stbl.Stts
+	return stts != nil && len(stts.SampleCount) > 0
+}
+
 // IsFragmented - is file made of multiple segments (Mp4 fragments)
 func (f *File) IsFragmented() bool {
 	return f.isFragmented
@@ -592,17 +602,22 @@ func WithDecodeFlags(flags DecFileFlags) Option {
 
 // CopySampleData copies sample data from a track in a progressive mp4 file to w.
 // Use rs for lazy read and workSpace as an intermediate storage to avoid memory allocations.
+// An error is returned if there is no mdat box or the sample data is not inside its payload.
 func (f *File) CopySampleData(w io.Writer, rs io.ReadSeeker, trak *TrakBox,
 	startSampleNr, endSampleNr uint32, workSpace []byte) error {
 	if f.isFragmented {
 		return fmt.Errorf("only available for progressive files")
 	}
 	mdat := f.Mdat
+	if mdat == nil {
+		return fmt.Errorf("no mdat box")
+	}
 
 	if mdat.IsLazy() && rs == nil {
 		return fmt.Errorf("no ReadSeeker for lazy mdat")
 	}
 	mdatPayloadStart := mdat.PayloadAbsoluteOffset()
+	mdatPayloadEnd := mdatPayloadStart + mdat.Size() - mdat.HeaderSize()
 
 	stbl := trak.Mdia.Minf.Stbl
 	chunks, err := stbl.Stsc.GetContainingChunks(startSampleNr, endSampleNr)
@@ -643,6 +658,10 @@ func (f *File) CopySampleData(w io.Writer, rs io.ReadSeeker, trak *TrakBox,
 		for sNr := startNr; sNr <= endNr; sNr++ {
 			size += int64(stbl.Stsz.GetSampleSize(int(sNr)))
 		}
+		if offset < mdatPayloadStart || offset+uint64(size) > mdatPayloadEnd {
+			return fmt.Errorf("chunk %d data [%d, %d) not inside mdat payload [%d, %d)",
+				chunk.ChunkNr, offset, offset+uint64(size), mdatPayloadStart, mdatPayloadEnd)
+		}
 		if mdat.IsLazy() {
 			_, err := rs.Seek(int64(offset), io.SeekStart)
 			if err != nil {
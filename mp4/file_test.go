@@ -121,6 +121,64 @@ func TestCopyTrackSampleData(t *testing.T) {
 	}
 }
 
+func TestCopySampleDataWithEmptyMdatFirst(t *testing.T) {
+	rawInput, err := os.ReadFile("./testdata/prog_8s.mp4")
+	if err != nil {
+		t.Fatal(err)
+	}
+	orig, err := mp4.DecodeFile(bytes.NewReader(rawInput))
+	if err != nil {
+		t.Fatal(err)
+	}
+	mdatStart := int(orig.Mdat.StartPos)
+	emptyMdat := []byte{0, 0, 0, 8, 'm', 'd', 'a', 't'}
+	var startSampleNr uint32 = 31
+	var endSampleNr uint32 = 60
+	wantData := bytes.Buffer{}
+	err = orig.CopySampleData(&wantData, nil, orig.Moov.Trak, startSampleNr, endSampleNr, nil)
+	if err != nil {
+		t.Fatal(err)
+	}
+
+	// Insert an empty mdat before the real one and shift the chunk offsets accordingly
+	data := make([]byte, 0, len(rawInput)+len(emptyMdat))
+	data = append(data, rawInput[:mdatStart]...)
+	data = append(data, emptyMdat...)
+	data = append(data, rawInput[mdatStart:]...)
+	for _, mode := range []mp4.DecFileMode{mp4.DecModeNormal, mp4.DecModeLazyMdat} {
+		rs := bytes.NewReader(data)
+		mp4f, err := mp4.DecodeFile(rs, mp4.WithDecodeMode(mode))
+		if err != nil {
+			t.Fatal(err)
+		}
+		stco := mp4f.Moov.Trak.Mdia.Minf.Stbl.Stco
+		for i := range stco.ChunkOffset {
+			stco.ChunkOffset[i] += uint32(len(emptyMdat))
+		}
+		gotData := bytes.Buffer{}
+		err = mp4f.CopySampleData(&gotData, rs, mp4f.Moov.Trak, startSampleNr, endSampleNr, nil)
+		if err != nil {
+			t.Error(err)
+			continue
+		}
+		if !bytes.Equal(gotData.Bytes(), wantData.Bytes()) {
+			t.Errorf("mode %d: sample data differs", mode)
+		}
+	}
+
+	// No mdat, or only an empty mdat, should give errors
+	for _, noData := range [][]byte{rawInput[:mdatStart], append(rawInput[:mdatStart:mdatStart], emptyMdat...)} {
+		mp4f, err := mp4.DecodeFile(bytes.NewReader(noData))
+		if err != nil {
+			t.Fatal(err)
+		}
+		err = mp4f.CopySampleData(&bytes.Buffer{}, nil, mp4f.Moov.Trak, startSampleNr, endSampleNr, nil)
+		if err == nil {
+			t.Errorf("expected error for file without sample data")
+		}
+	}
+}
+
 func TestDecodeEncode(t *testing.T) {
 	testFiles := []string{
 		"./testdata/prog_8s.mp4",
@@ -404,3 +462,82 @@ func TestDecodeTrunctedFile(t *testing.T) {
 		t.Error("expected styp box to be present in truncated file")
 	}
 }
+
+func TestDecodeFileWithMdatSizeZero(t *testing.T) {
+	rawInput, err := os.ReadFile("./testdata/prog_8s.mp4")
+	if err != nil {
+		t.Fatal(err)
+	}
+	orig, err := mp4.DecodeFile(bytes.NewReader(rawInput))
+	if err != nil {
+		t.Fatal(err)
+	}
+	// Drop the boxes after mdat and set the mdat size to 0, meaning that it extends to end of file
+	mdatStart := int(orig.Mdat.StartPos)
+	mdatEnd := mdatStart + int(orig.Mdat.Size())
+	data := make([]byte, mdatEnd)
+	copy(data, rawInput[:mdatEnd])
+	copy(data[mdatStart:mdatStart+4], []byte{0, 0, 0, 0})
+
+	for _, mode := range []mp4.DecFileMode{mp4.DecModeNormal, mp4.DecModeLazyMdat} {
+		mp4f, err := mp4.DecodeFile(bytes.NewReader(data), mp4.WithDecodeMode(mode))
+		if err != nil {
+			t.Fatal(err)
+		}
+		if mp4f.Mdat.Size() != orig.Mdat.Size() {
+			t.Errorf("mode %d: got mdat size %d instead of %d", mode, mp4f.Mdat.Size(), orig.Mdat.Size())
+		}
+	}
+
+	mp4f, err := mp4.DecodeFileSR(bits.NewFixedSliceReader(data))
+	if err != nil {
+		t.Fatal(err)
+	}
+	if mp4f.Mdat.Size() != orig.Mdat.Size() {
+		t.Errorf("slice reader: got mdat size %d instead of %d", mp4f.Mdat.Size(), orig.Mdat.Size())
+	}
+	out := bytes.Buffer{}
+	err = mp4f.Encode(&out)
+	if err != nil {
+		t.Fatal(err)
+	}
+	if !bytes.Equal(out.Bytes(), rawInput[:mdatEnd]) {
+		t.Error("encoded file differs from input with explicit mdat size")
+	}
+}
+
+func TestDecodeFileWithBadFirstTrak(t *testing.T) {
+	rawInput, err := os.ReadFile("./testdata/prog_8s.mp4")
+	if err != nil {
+		t.Fatal(err)
+	}
+	// The first trak (audio) starts at 157 with its tkhd at 165. Make the tkhd size too big
+	data := make([]byte, len(rawInput))
+	copy(data, rawInput)
+	copy(data[165:169], []byte{0, 0, 0xff, 0xff})
+
+	fromReader, err := mp4.DecodeFile(bytes.NewReader(data))
+	if err != nil {
+		t.Fatal(err)
+	}
+	fromSR, err := mp4.DecodeFileSR(bits.NewFixedSliceReader(data))
+	if err != nil {
+		t.Fatal(err)
+	}
+	for _, mp4f := range []*mp4.File{fromReader, fromSR} {
+		moov := mp4f.Moov
+		if len(moov.TrakErrs) != 1 {
+			t.Fatalf("got %d trak errors instead of 1", len(moov.TrakErrs))
+		}
+		if len(moov.Traks) != 1 || moov.Trak.Mdia.Hdlr.HandlerType != "vide" {
+			t.Fatalf("got %d traks instead of the video trak", len(moov.Traks))
+		}
+		out := bytes.Buffer{}
+		if err := mp4f.Encode(&out); err != nil {
+			t.Fatal(err)
+		}
+		if !bytes.Equal(out.Bytes(), data) {
+			t.Error("encoded file differs from input")
+		}
+	}
+}
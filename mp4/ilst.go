@@ -1,6 +1,7 @@
 package mp4
 
 import (
+	"encoding/binary"
 	"io"
 
 	"github.com/Eyevinn/mp4ff/bits"
@@ -25,7 +26,7 @@ func DecodeIlstSR(hdr BoxHeader, startPos uint64, sr bits.SliceReader) (Box, err
 	}
 	b := &IlstBox{}
 	for _, c := range children {
-		b.AddChild(c)
+		b.AddChild(decodeKeyIndexItem(c))
 	}
 	return b, nil
 }
@@ -38,11 +39,31 @@ func DecodeIlst(hdr BoxHeader, startPos uint64, r io.Reader) (Box, error) {
 	}
 	b := &IlstBox{}
 	for _, c := range children {
-		b.AddChild(c)
+		b.AddChild(decodeKeyIndexItem(c))
 	}
 	return b, nil
 }
 
+// decodeKeyIndexItem - decode item with a 1-based key index as type (QuickTime keys) as container of data boxes.
+// Other items, and items whose payload is not a sequence of boxes, are returned unchanged.
+func decodeKeyIndexItem(c Box) Box {
+	u, ok := c.(*UnknownBox)
+	if !ok || u.name == "" || u.name[0] != 0 {
+		return c
+	}
+	payload := u.Payload()
+	hdr := BoxHeader{Name: u.name, Size: u.size}
+	children, err := DecodeContainerChildrenSR(hdr, 0, uint64(len(payload)), bits.NewFixedSliceReader(payload))
+	if err != nil {
+		return c
+	}
+	item := NewGenericContainerBox(u.name)
+	for _, ic := range children {
+		item.AddChild(ic)
+	}
+	return item
+}
+
 // Type - box-specific type
 func (b *IlstBox) Type() string {
 	return "ilst"
@@ -72,3 +93,130 @@ func (b *IlstBox) EncodeSW(sw bits.SliceWriter) error {
 func (b *IlstBox) Info(w io.Writer, specificBoxLevels, indent, indentStep string) error {
 	return ContainerInfo(b, w, specificBoxLevels, indent, indentStep)
 }
+
+// Text - text of the first data box of item with type itemType, e.g. "\xa9nam", "\xa9ART", "\xa9wrt", or "\xa9day".
+// Returns an empty string if the item is not found.
+func (b *IlstBox) Text(itemType string) string {
+	data, ok := b.itemData(itemType)
+	if !ok {
+		return ""
+	}
+	return string(data)
+}
+
+// Genre - genre from free-text ©gen item, or from predefined gnre item if ©gen is absent.
+// Returns an empty string if no genre is found.
+func (b *IlstBox) Genre() string {
+	if data, ok := b.itemData("\xa9gen"); ok {
+		return string(data)
+	}
+	if data, ok := b.itemData("gnre"); ok && len(data) == 2 {
+		return GenreName(binary.BigEndian.Uint16(data))
+	}
+	return ""
+}
+
+// TrackNumber - track number and total number of tracks from trkn item. Zero values if not present.
+func (b *IlstBox) TrackNumber() (number, total uint16) {
+	return b.indexAndTotal("trkn")
+}
+
+// DiscNumber - disc number and total number of discs from disk item. Zero values if not present.
+func (b *IlstBox) DiscNumber() (number, total uint16) {
+	return b.indexAndTotal("disk")
+}
+
+// indexAndTotal - decode binary data with 2 reserved bytes, followed by 16-bit index and total
+func (b *IlstBox) indexAndTotal(itemType string) (index, total uint16) {
+	data, ok := b.itemData(itemType)
+	if !ok || len(data) < 6 {
+		return 0, 0
+	}
+	return binary.BigEndian.Uint16(data[2:4]), binary.BigEndian.Uint16(data[4:6])
+}
+
+// CoverArt - image data and data type (DataTypeJPEG, DataTypePNG, or DataTypeBMP) of first image in covr item.
+// ok is false if no image is found.
+func (b *IlstBox) CoverArt() (image []byte, dataType uint32, ok bool) {
+	for _, data := range b.itemDataBoxes("covr") {
+		switch data.DataType {
+		case DataTypeJPEG, DataTypePNG, DataTypeBMP:
+			return data.Data, data.DataType, true
+		}
+	}
+	return nil, 0, false
+}
+
+// ValueForKey - first data box of the item referring to key in keys, e.g. "com.apple.quicktime.make".
+// ok is false if the key or the item is not found.
+func (b *IlstBox) ValueForKey(keys *KeysBox, key string) (value *DataBox, ok bool) {
+	for i, e := range keys.Entries {
+		if e.Value != key {
+			continue
+		}
+		itemType := make([]byte, 4)
+		binary.BigEndian.PutUint32(itemType, uint32(i+1))
+		dataBoxes := b.itemDataBoxes(string(itemType))
+		if len(dataBoxes) == 0 {
+			return nil, false
+		}
+		return dataBoxes[0], true
+	}
+	return nil, false
+}
+
+// itemData - payload of first data box of first item with type itemType
+func (b *IlstBox) itemData(itemType string) ([]byte, bool) {
+	dataBoxes := b.itemDataBoxes(itemType)
+	if len(dataBoxes) == 0 {
+		return nil, false
+	}
+	return dataBoxes[0].Data, true
+}
+
+// itemDataBoxes - all data boxes of first item with type itemType
+func (b *IlstBox) itemDataBoxes(itemType string) []*DataBox {
+	for _, c := range b.Children {
+		item, ok := c.(*GenericContainerBox)
+		if !ok || item.Type() != itemType {
+			continue
+		}
+		var dataBoxes []*DataBox
+		for _, ic := range item.Children {
+			if data, ok := ic.(*DataBox); ok {
+				dataBoxes = append(dataBoxes, data)
+			}
+		}
+		return dataBoxes
+	}
+	return nil
+}
+
+// GenreName - name of predefined genre given 1-based gnre index (ID3v1 genre + 1).
+// Returns an empty string for unknown index.
+func GenreName(index uint16) string {
+	if index == 0 || int(index) > len(id3v1Genres) {
+		return ""
+	}
+	return id3v1Genres[index-1]
+}
+
+// id3v1Genres - ID3v1 genres including Winamp extensions
+var id3v1Genres = []string{
+	"Blues", "Classic Rock", "Country", "Dance", "Disco", "Funk", "Grunge", "Hip-Hop",
+	"Jazz", "Metal", "New Age", "Oldies", "Other", "Pop", "R&B", "Rap",
+	"Reggae", "Rock", "Techno", "Industrial", "Alternative", "Ska", "Death Metal", "Pranks",
+	"Soundtrack", "Euro-Techno", "Ambient", "Trip-Hop", "Vocal", "Jazz+Funk", "Fusion", "Trance",
+	"Classical", "Instrumental", "Acid", "House", "Game", "Sound Clip", "Gospel", "Noise",
+	"AlternRock", "Bass", "Soul", "Punk", "Space", "Meditative", "Instrumental Pop", "Instrumental Rock",
+	"Ethnic", "Gothic", "Darkwave", "Techno-Industrial", "Electronic", "Pop-Folk", "Eurodance", "Dream",
+	"Southern Rock", "Comedy", "Cult", "Gangsta", "Top 40", "Christian Rap", "Pop/Funk", "Jungle",
+	"Native American", "Cabaret", "New Wave", "Psychadelic", "Rave", "Showtunes", "Trailer", "Lo-Fi",
+	"Tribal", "Acid Punk", "Acid Jazz", "Polka", "Retro", "Musical", "Rock & Roll", "Hard Rock",
+	"Folk", "Folk-Rock", "National Folk", "Swing", "Fast Fusion", "Bebob", "Latin", "Revival",
+	"Celtic", "Bluegrass", "Avantgarde", "Gothic Rock", "Progressive Rock", "Psychedelic Rock", "Symphonic Rock", "Slow Rock",
+	"Big Band", "Chorus", "Easy Listening", "Acoustic", "Humour", "Speech", "Chanson", "Opera",
+	"Chamber Music", "Sonata", "Symphony", "Booty Bass", "Primus", "Porn Groove", "Satire", "Slow Jam",
+	"Club", "Tango", "Samba", "Folklore", "Ballad", "Power Ballad", "Rhythmic Soul", "Freestyle",
+	"Duet", "Punk Rock", "Drum Solo", "A capella", "Euro-House", "Dance Hall",
+}
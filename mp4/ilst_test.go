@@ -0,0 +1,132 @@
+package mp4_test
+
+import (
+	"bytes"
+	"testing"
+
+	"github.com/Eyevinn/mp4ff/mp4"
+)
+
+func TestIlstGenre(t *testing.T) {
+	gnre := mp4.NewGenericContainerBox("gnre")
+	gnre.Children = append(gnre.Children, mp4.NewDataBox(mp4.DataTypeBinary, []byte{0x00, 0x15}))
+	ilst := &mp4.IlstBox{}
+	ilst.AddChild(gnre)
+
+	buf := bytes.Buffer{}
+	err := ilst.Encode(&buf)
+	if err != nil {
+		t.Fatal(err)
+	}
+	box, err := mp4.DecodeBox(0, &buf)
+	if err != nil {
+		t.Fatal(err)
+	}
+	decIlst := box.(*mp4.IlstBox)
+	if got := decIlst.Genre(); got != "Alternative" {
+		t.Errorf("got genre %q instead of %q", got, "Alternative")
+	}
+
+	gen := mp4.NewGenericContainerBox("\xa9gen")
+	gen.Children = append(gen.Children, &mp4.DataBox{DataType: mp4.DataTypeUTF8, Data: []byte("Chiptune")})
+	decIlst.AddChild(gen)
+	if got := decIlst.Genre(); got != "Chiptune" {
+		t.Errorf("got genre %q instead of %q", got, "Chiptune")
+	}
+}
+
+func TestGenreName(t *testing.T) {
+	testCases := []struct {
+		index  uint16
+		wanted string
+	}{
+		{0, ""},
+		{1, "Blues"},
+		{21, "Alternative"},
+		{126, "Dance Hall"},
+		{127, ""},
+	}
+	for _, tc := range testCases {
+		if got := mp4.GenreName(tc.index); got != tc.wanted {
+			t.Errorf("index %d: got %q instead of %q", tc.index, got, tc.wanted)
+		}
+	}
+}
+
+func TestIlstItems(t *testing.T) {
+	ilst := &mp4.IlstBox{}
+	items := []struct {
+		itemType string
+		dataType uint32
+		data     []byte
+	}{
+		{"\xa9alb", mp4.DataTypeUTF8, []byte("An Album")},
+		{"\xa9wrt", mp4.DataTypeUTF8, []byte("A Composer")},
+		{"\xa9day", mp4.DataTypeUTF8, []byte("2021")},
+		{"\xa9cmt", mp4.DataTypeUTF8, []byte("A comment")},
+		{"\xa9too", mp4.DataTypeUTF8, []byte("Lavf58.76.100")},
+		{"trkn", mp4.DataTypeBinary, []byte{0, 0, 0, 3, 0, 12, 0, 0}},
+		{"disk", mp4.DataTypeBinary, []byte{0, 0, 0, 1, 0, 2}},
+	}
+	for _, it := range items {
+		item := mp4.NewGenericContainerBox(it.itemType)
+		item.Children = append(item.Children, mp4.NewDataBox(it.dataType, it.data))
+		ilst.AddChild(item)
+	}
+
+	buf := bytes.Buffer{}
+	err := ilst.Encode(&buf)
+	if err != nil {
+		t.Fatal(err)
+	}
+	box, err := mp4.DecodeBox(0, &buf)
+	if err != nil {
+		t.Fatal(err)
+	}
+	decIlst := box.(*mp4.IlstBox)
+	for _, it := range items[:5] {
+		if got := decIlst.Text(it.itemType); got != string(it.data) {
+			t.Errorf("%s: got %q instead of %q", it.itemType, got, it.data)
+		}
+	}
+	if got := decIlst.Text("\xa9nam"); got != "" {
+		t.Errorf("got title %q instead of empty string", got)
+	}
+	if nr, total := decIlst.TrackNumber(); nr != 3 || total != 12 {
+		t.Errorf("got track number %d/%d instead of 3/12", nr, total)
+	}
+	if nr, total := decIlst.DiscNumber(); nr != 1 || total != 2 {
+		t.Errorf("got disc number %d/%d instead of 1/2", nr, total)
+	}
+}
+
+func TestIlstCoverArt(t *testing.T) {
+	ilst := &mp4.IlstBox{}
+	if _, _, ok := ilst.CoverArt(); ok {
+		t.Error("got cover art from empty ilst")
+	}
+	png := []byte{0x89, 'P', 'N', 'G', 0x0d, 0x0a, 0x1a, 0x0a}
+	covr := mp4.NewGenericContainerBox("covr")
+	covr.Children = append(covr.Children, &mp4.DataBox{DataType: mp4.DataTypeUTF8, Data: []byte("not an image")})
+	covr.Children = append(covr.Children, &mp4.DataBox{DataType: mp4.DataTypePNG, Data: png})
+	covr.Children = append(covr.Children, &mp4.DataBox{DataType: mp4.DataTypeJPEG, Data: []byte{0xff, 0xd8, 0xff}})
+	ilst.AddChild(covr)
+	boxDiffAfterEncodeAndDecode(t, ilst)
+
+	buf := bytes.Buffer{}
+	err := ilst.Encode(&buf)
+	if err != nil {
+		t.Fatal(err)
+	}
+	box, err := mp4.DecodeBox(0, &buf)
+	if err != nil {
+		t.Fatal(err)
+	}
+	image, dataType, ok := box.(*mp4.IlstBox).CoverArt()
+	if !ok {
+		t.Fatal("no cover art found")
+	}
+	if dataType != mp4.DataTypePNG || !bytes.Equal(image, png) {
+		t.Errorf("got cover art type %d with %d bytes instead of PNG image", dataType, len(image))
+	}
+}
@@ -102,6 +102,46 @@ func (s *InitSegment) AddEmptyTrack(timeScale uint32, mediaType, language string
 	moov.Mvex.AddChild(CreateTrex(trackID))
 }
 
+// CreateInitFromProgressive - create a single-track init segment for the track with trackID in a progressive moov.
+// The sample description entries of the track are kept, while the sample tables are empty.
+// The track gets track ID 1 in the init segment.
+func CreateInitFromProgressive(moov *MoovBox, trackID uint32) (*InitSegment, error) {
+	inTrak, ok := moov.GetTrak(trackID)
+	if !ok {
+		return nil, fmt.Errorf("no track with trackID %d", trackID)
+	}
+	var mediaType string
+	switch hdlrType := inTrak.Mdia.Hdlr.HandlerType; hdlrType {
+	case "vide":
+		mediaType = "video"
+	case "soun":
+		mediaType = "audio"
+	case "subt":
+		mediaType = "subtitle"
+	case "text":
+		mediaType = "text"
+	default:
+		return nil, fmt.Errorf("hdlr type %q not supported", hdlrType)
+	}
+	lang := inTrak.Mdia.Mdhd.GetLanguage()
+	if inTrak.Mdia.Elng != nil {
+		lang = inTrak.Mdia.Elng.Language
+	}
+
+	init := CreateEmptyInit()
+	init.Moov.Mvhd.Timescale = moov.Mvhd.Timescale
+	init.Moov.Mvex.AddChild(&MehdBox{FragmentDuration: int64(moov.Mvhd.Duration)})
+	init.AddEmptyTrack(inTrak.Mdia.Mdhd.Timescale, mediaType, lang)
+	outTrak := init.Moov.Trak
+	outTrak.Tkhd.Width = inTrak.Tkhd.Width
+	outTrak.Tkhd.Height = inTrak.Tkhd.Height
+	outStsd := outTrak.Mdia.Minf.Stbl.Stsd
+	for _, sampleEntry := range inTrak.Mdia.Minf.Stbl.Stsd.Children {
+		outStsd.AddChild(sampleEntry)
+	}
+	return init, nil
+}
+
 // CreateEmptyTrak - create a full trak-tree for an empty (fragmented) track with no samples or stsd content
 func CreateEmptyTrak(trackID, timeScale uint32, mediaType, language string) *TrakBox {
 	/*  Built tree like
@@ -1,6 +1,7 @@
 package mp4_test
 
 import (
+	"bytes"
 	"encoding/hex"
 	"fmt"
 	"testing"
@@ -228,3 +229,41 @@ func createSubtitlesStppInitSegment() (*mp4.InitSegment, error) {
 	}
 	return init, nil
 }
+
+func TestCreateInitFromProgressive(t *testing.T) {
+	progFile, err := mp4.ReadMP4File("testdata/prog_8s.mp4")
+	if err != nil {
+		t.Fatal(err)
+	}
+	_, err = mp4.CreateInitFromProgressive(progFile.Moov, 3)
+	if err == nil {
+		t.Error("expected error for non-existing track")
+	}
+	init, err := mp4.CreateInitFromProgressive(progFile.Moov, 2)
+	if err != nil {
+		t.Fatal(err)
+	}
+	buf := bytes.Buffer{}
+	err = init.Encode(&buf)
+	if err != nil {
+		t.Fatal(err)
+	}
+	decFile, err := mp4.DecodeFile(&buf)
+	if err != nil {
+		t.Fatal(err)
+	}
+	if decFile.Init == nil {
+		t.Fatal("decoded file has no init segment")
+	}
+	moov := decFile.Init.Moov
+	if moov.Mvex == nil || moov.Mvex.Trex == nil || moov.Mvex.Trex.TrackID != 1 {
+		t.Error("no trex for track 1 in init segment")
+	}
+	stbl := moov.Trak.Mdia.Minf.Stbl
+	if stbl.Stsd.AvcX == nil || stbl.Stsd.AvcX.AvcC == nil {
+		t.Error("no avcC in init segment")
+	}
+	if stbl.Stsz.GetNrSamples() != 0 {
+		t.Errorf("got %d samples in init segment", stbl.Stsz.GetNrSamples())
+	}
+}
@@ -0,0 +1,56 @@
+package mp4
+
+import (
+	"fmt"
+	"sort"
+)
+
+// InterleaveInfo - placement of the chunks of different tracks in a progressive file
+type InterleaveInfo struct {
+	NrTracks int // Number of tracks with chunks
+	NrChunks int
+	// NrTrackSwitches - number of times the next chunk in file order belongs to another track
+	NrTrackSwitches int
+	// Interleaved is true if chunks of different tracks alternate, so that the file can be
+	// played while downloading. It is false if each track has its chunks in one block.
+	Interleaved bool
+}
+
+// InterleaveInfo - analyse how the chunks of the tracks are interleaved based on stco/co64 offsets
+func (m *MoovBox) InterleaveInfo() (InterleaveInfo, error) {
+	type chunk struct {
+		offset  uint64
+		trackID uint32
+	}
+	var info InterleaveInfo
+	var chunks []chunk
+	for _, trak := range m.Traks {
+		stbl := trak.Mdia.Minf.Stbl
+		trackID := trak.Tkhd.TrackID
+		nrChunksBefore := len(chunks)
+		switch {
+		case stbl.Stco != nil:
+			for _, offset := range stbl.Stco.ChunkOffset {
+				chunks = append(chunks, chunk{uint64(offset), trackID})
+			}
+		case stbl.Co64 != nil:
+			for _, offset := range stbl.Co64.ChunkOffset {
+				chunks = append(chunks, chunk{offset, trackID})
+			}
+		default:
+			return info, fmt.Errorf("track %d: neither stco nor co64 available", trackID)
+		}
+		if len(chunks) > nrChunksBefore {
+			info.NrTracks++
+		}
+	}
+	sort.SliceStable(chunks, func(i, j int) bool { return chunks[i].offset < chunks[j].offset })
+	for i := 1; i < len(chunks); i++ {
+		if chunks[i].trackID != chunks[i-1].trackID {
+			info.NrTrackSwitches++
+		}
+	}
+	info.NrChunks = len(chunks)
+	info.Interleaved = info.NrTracks > 1 && info.NrTrackSwitches > info.NrTracks-1
+	return info, nil
+}
@@ -0,0 +1,42 @@
+package mp4_test
+
+import (
+	"os"
+	"testing"
+
+	"github.com/Eyevinn/mp4ff/mp4"
+)
+
+func TestInterleaveInfo(t *testing.T) {
+	fd, err := os.Open("./testdata/prog_8s.mp4")
+	if err != nil {
+		t.Fatal(err)
+	}
+	defer fd.Close()
+	mp4f, err := mp4.DecodeFile(fd, mp4.WithDecodeMode(mp4.DecModeLazyMdat))
+	if err != nil {
+		t.Fatal(err)
+	}
+	info, err := mp4f.Moov.InterleaveInfo()
+	if err != nil {
+		t.Fatal(err)
+	}
+	wanted := mp4.InterleaveInfo{NrTracks: 2, NrChunks: 33, NrTrackSwitches: 32, Interleaved: true}
+	if info != wanted {
+		t.Errorf("got %+v instead of %+v", info, wanted)
+	}
+
+	// Move all chunks of the second track after the first track's chunks
+	stco := mp4f.Moov.Traks[1].Mdia.Minf.Stbl.Stco
+	for i := range stco.ChunkOffset {
+		stco.ChunkOffset[i] += 1 << 20
+	}
+	info, err = mp4f.Moov.InterleaveInfo()
+	if err != nil {
+		t.Fatal(err)
+	}
+	wanted = mp4.InterleaveInfo{NrTracks: 2, NrChunks: 33, NrTrackSwitches: 1, Interleaved: false}
+	if info != wanted {
+		t.Errorf("got %+v instead of %+v", info, wanted)
+	}
+}
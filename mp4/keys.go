@@ -0,0 +1,117 @@
+package mp4
+
+import (
+	"fmt"
+	"io"
+
+	"github.com/Eyevinn/mp4ff/bits"
+)
+
+// KeysBox - QuickTime Metadata Item Keys Atom (keys)
+//
+// Contained in: QuickTime meta atom.
+// The items in the sibling ilst atom refer to these keys by 1-based index.
+// See https://developer.apple.com/library/archive/documentation/QuickTime/QTFF/Metadata/Metadata.html
+type KeysBox struct {
+	Version byte
+	Flags   uint32
+	Entries []KeyEntry
+}
+
+// KeyEntry - a key namespace (typically "mdta") and a key value such as "com.apple.quicktime.make"
+type KeyEntry struct {
+	Namespace string
+	Value     string
+}
+
+// DecodeKeys - box-specific decode
+func DecodeKeys(hdr BoxHeader, startPos uint64, r io.Reader) (Box, error) {
+	data, err := readBoxBody(r, hdr)
+	if err != nil {
+		return nil, err
+	}
+	sr := bits.NewFixedSliceReader(data)
+	return DecodeKeysSR(hdr, startPos, sr)
+}
+
+// DecodeKeysSR - box-specific decode
+func DecodeKeysSR(hdr BoxHeader, startPos uint64, sr bits.SliceReader) (Box, error) {
+	versionAndFlags := sr.ReadUint32()
+	entryCount := sr.ReadUint32()
+	b := KeysBox{
+		Version: byte(versionAndFlags >> 24),
+		Flags:   versionAndFlags & flagsMask,
+	}
+	for i := uint32(0); i < entryCount; i++ {
+		keySize := sr.ReadUint32()
+		if keySize < 8 || int(keySize-4) > sr.NrRemainingBytes() {
+			return nil, fmt.Errorf("decode keys: bad key size %d", keySize)
+		}
+		namespace := sr.ReadFixedLengthString(4)
+		value := sr.ReadFixedLengthString(int(keySize - 8))
+		b.Entries = append(b.Entries, KeyEntry{Namespace: namespace, Value: value})
+	}
+	if err := sr.AccError(); err != nil {
+		return nil, fmt.Errorf("decode keys: %w", err)
+	}
+	return &b, nil
+}
+
+// Type - box type
+func (b *KeysBox) Type() string {
+	return "keys"
+}
+
+// Size - calculated size of box
+func (b *KeysBox) Size() uint64 {
+	size := uint64(boxHeaderSize + 8)
+	for _, e := range b.Entries {
+		size += uint64(8 + len(e.Value))
+	}
+	return size
+}
+
+// Encode - write box to w
+func (b *KeysBox) Encode(w io.Writer) error {
+	sw := bits.NewFixedSliceWriter(int(b.Size()))
+	err := b.EncodeSW(sw)
+	if err != nil {
+		return err
+	}
+	_, err = w.Write(sw.Bytes())
+	return err
+}
+
+// EncodeSW - box-specific encode to slicewriter
+func (b *KeysBox) EncodeSW(sw bits.SliceWriter) error {
+	err := EncodeHeaderSW(b, sw)
+	if err != nil {
+		return err
+	}
+	versionAndFlags := (uint32(b.Version) << 24) + b.Flags
+	sw.WriteUint32(versionAndFlags)
+	sw.WriteUint32(uint32(len(b.Entries)))
+	for _, e := range b.Entries {
+		sw.WriteUint32(uint32(8 + len(e.Value)))
+		sw.WriteString(e.Namespace, false)
+		sw.WriteString(e.Value, false)
+	}
+	return sw.AccError()
+}
+
+// Info - write box-specific information
+func (b *KeysBox) Info(w io.Writer, specificBoxLevels, indent, indentStep string) error {
+	bd := newInfoDumper(w, indent, b, int(b.Version), b.Flags)
+	for i, e := range b.Entries {
+		bd.write(" - key[%d]: %s %s", i+1, e.Namespace, e.Value)
+	}
+	return bd.err
+}
+
+// KeyValue returns the key value for a 1-based index as used by ilst items. Empty string if not found.
+func (b *KeysBox) KeyValue(index uint32) string {
+	if index == 0 || int(index) > len(b.Entries) {
+		return ""
+	}
+	return b.Entries[index-1].Value
+}
@@ -0,0 +1,143 @@
+package mp4_test
+
+import (
+	"bytes"
+	"encoding/hex"
+	"testing"
+
+	"github.com/Eyevinn/mp4ff/bits"
+	"github.com/Eyevinn/mp4ff/mp4"
+)
+
+func TestKeys(t *testing.T) {
+	t.Run("encode and decode", func(t *testing.T) {
+		keys := &mp4.KeysBox{
+			Entries: []mp4.KeyEntry{
+				{Namespace: "mdta", Value: "com.apple.quicktime.make"},
+				{Namespace: "mdta", Value: "com.apple.quicktime.location.ISO6709"},
+			},
+		}
+		boxDiffAfterEncodeAndDecode(t, keys)
+	})
+	t.Run("decode make and model keys", func(t *testing.T) {
+		rawHex := ("000000516b657973000000000000000200000020" +
+			"6d647461636f6d2e6170706c652e717569636b74696d652e6d616b65" +
+			"000000216d647461636f6d2e6170706c652e717569636b74696d652e6d6f64656c")
+		rawBytes, err := hex.DecodeString(rawHex)
+		if err != nil {
+			t.Error(err)
+		}
+		cmpAfterDecodeEncodeBox(t, rawBytes)
+		box, err := mp4.DecodeBox(0, bytes.NewReader(rawBytes))
+		if err != nil {
+			t.Errorf("Error decoding keys box: %v", err)
+		}
+		keys := box.(*mp4.KeysBox)
+		if keys.KeyValue(1) != "com.apple.quicktime.make" {
+			t.Errorf("Expected key 1 'com.apple.quicktime.make', got '%s'", keys.KeyValue(1))
+		}
+		if keys.KeyValue(2) != "com.apple.quicktime.model" {
+			t.Errorf("Expected key 2 'com.apple.quicktime.model', got '%s'", keys.KeyValue(2))
+		}
+		if keys.KeyValue(3) != "" {
+			t.Errorf("Expected no key 3, got '%s'", keys.KeyValue(3))
+		}
+	})
+	t.Run("bad key size", func(t *testing.T) {
+		rawBytes, _ := hex.DecodeString("000000186b6579730000000000000001000000ff6d647461")
+		_, err := mp4.DecodeBox(0, bytes.NewReader(rawBytes))
+		if err == nil {
+			t.Error("expected error for too large key size")
+		}
+	})
+}
+
+func TestIlstValueForKey(t *testing.T) {
+	keys := &mp4.KeysBox{
+		Entries: []mp4.KeyEntry{
+			{Namespace: "mdta", Value: "com.apple.quicktime.make"},
+			{Namespace: "mdta", Value: "com.apple.quicktime.model"},
+			{Namespace: "mdta", Value: "com.apple.quicktime.software"},
+		},
+	}
+	ilst := &mp4.IlstBox{}
+	for _, item := range []struct {
+		index byte
+		value string
+	}{{2, "iPhone 12"}, {1, "Apple"}} {
+		box := mp4.NewGenericContainerBox(string([]byte{0, 0, 0, item.index}))
+		box.AddChild(mp4.NewDataBox(mp4.DataTypeUTF8, []byte(item.value)))
+		ilst.AddChild(box)
+	}
+	buf := bytes.Buffer{}
+	if err := ilst.Encode(&buf); err != nil {
+		t.Fatal(err)
+	}
+	encIlst := buf.Bytes()
+	box, err := mp4.DecodeBox(0, bytes.NewReader(encIlst))
+	if err != nil {
+		t.Fatal(err)
+	}
+	boxSR, err := mp4.DecodeBoxSR(0, bits.NewFixedSliceReader(encIlst))
+	if err != nil {
+		t.Fatal(err)
+	}
+	for _, decIlst := range []*mp4.IlstBox{box.(*mp4.IlstBox), boxSR.(*mp4.IlstBox)} {
+		camMake, ok := decIlst.ValueForKey(keys, "com.apple.quicktime.make")
+		if !ok || string(camMake.Data) != "Apple" || camMake.DataType != mp4.DataTypeUTF8 {
+			t.Errorf("got make %v, %t instead of Apple", camMake, ok)
+		}
+		model, ok := decIlst.ValueForKey(keys, "com.apple.quicktime.model")
+		if !ok || string(model.Data) != "iPhone 12" {
+			t.Errorf("got model %v, %t instead of iPhone 12", model, ok)
+		}
+		if _, ok := decIlst.ValueForKey(keys, "com.apple.quicktime.software"); ok {
+			t.Error("got value for key without item")
+		}
+		if _, ok := decIlst.ValueForKey(keys, "com.apple.quicktime.author"); ok {
+			t.Error("got value for unknown key")
+		}
+		if decIlst.Size() != uint64(len(encIlst)) {
+			t.Errorf("got decoded ilst size %d instead of %d", decIlst.Size(), len(encIlst))
+		}
+	}
+}
+
+func TestIlstMalformedKeyIndexItem(t *testing.T) {
+	keys := &mp4.KeysBox{Entries: []mp4.KeyEntry{{Namespace: "mdta", Value: "com.apple.quicktime.make"}}}
+	ilst := &mp4.IlstBox{}
+	good := mp4.NewGenericContainerBox("\x00\x00\x00\x01")
+	good.AddChild(mp4.NewDataBox(mp4.DataTypeUTF8, []byte("Apple")))
+	ilst.AddChild(good)
+	// The payload starts like a box of size 32, but only 4 bytes are present
+	ilst.AddChild(mp4.CreateUnknownBox("\x00\x00\x00\x02", 12, []byte{0, 0, 0, 0x20}))
+	buf := bytes.Buffer{}
+	if err := ilst.Encode(&buf); err != nil {
+		t.Fatal(err)
+	}
+	encIlst := buf.Bytes()
+	box, err := mp4.DecodeBox(0, bytes.NewReader(encIlst))
+	if err != nil {
+		t.Fatal(err)
+	}
+	boxSR, err := mp4.DecodeBoxSR(0, bits.NewFixedSliceReader(encIlst))
+	if err != nil {
+		t.Fatal(err)
+	}
+	for _, decIlst := range []*mp4.IlstBox{box.(*mp4.IlstBox), boxSR.(*mp4.IlstBox)} {
+		if _, ok := decIlst.Children[1].(*mp4.UnknownBox); !ok {
+			t.Errorf("malformed item decoded as %T instead of UnknownBox", decIlst.Children[1])
+		}
+		camMake, ok := decIlst.ValueForKey(keys, "com.apple.quicktime.make")
+		if !ok || string(camMake.Data) != "Apple" {
+			t.Errorf("got make %v, %t instead of Apple", camMake, ok)
+		}
+		out := bytes.Buffer{}
+		if err := decIlst.Encode(&out); err != nil {
+			t.Fatal(err)
+		}
+		if !bytes.Equal(out.Bytes(), encIlst) {
+			t.Error("encoded ilst differs from input")
+		}
+	}
+}
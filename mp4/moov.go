@@ -19,6 +19,9 @@ type MoovBox struct {
 	Psshs    []*PsshBox
 	Children []Box
 	StartPos uint64
+	// TrakErrs - errors for trak boxes that could not be decoded.
+	// Such boxes are kept as UnknownBox in Children, but are not in Traks.
+	TrakErrs []error
 }
 
 // NewMoovBox - Generate a new empty moov box
@@ -70,30 +73,54 @@ func DecodeMoov(hdr BoxHeader, startPos uint64, r io.Reader) (Box, error) {
 		return nil, fmt.Errorf("moov: expected %d bytes, got %d", hdr.payloadLen(), len(data))
 	}
 	sr := bits.NewFixedSliceReader(data)
-	children, err := DecodeContainerChildrenSR(hdr, startPos+8, startPos+hdr.Size, sr)
-	if err != nil {
-		return nil, err
-	}
-	m := MoovBox{Children: make([]Box, 0, len(children))}
-	m.StartPos = startPos
-	for _, c := range children {
-		m.AddChild(c)
-	}
-	return &m, err
+	return DecodeMoovSR(hdr, startPos, sr)
 }
 
 // DecodeMoovSR - box-specific decode
+// A trak box that cannot be decoded does not stop the decoding. It is kept as an UnknownBox
+// and its error is added to TrakErrs, so that later tracks are still available.
 func DecodeMoovSR(hdr BoxHeader, startPos uint64, sr bits.SliceReader) (Box, error) {
-	children, err := DecodeContainerChildrenSR(hdr, startPos+8, startPos+hdr.Size, sr)
+	m := MoovBox{Children: make([]Box, 0, 8)}
+	m.StartPos = startPos
+	pos := startPos + uint64(hdr.Hdrlen)
+	endPos := startPos + hdr.Size
+	initPos := sr.GetPos()
+	for pos < endPos {
+		child, err := decodeMoovChildSR(&m, pos, sr)
+		if err != nil {
+			return nil, err
+		}
+		m.AddChild(child)
+		pos += child.Size()
+		if int(pos-startPos)-hdr.Hdrlen != sr.GetPos()-initPos {
+			return nil, fmt.Errorf("child %s size mismatch in moov", child.Type())
+		}
+	}
+	if pos != endPos {
+		return nil, fmt.Errorf("non-matching children box sizes in moov, parentSize=%d", hdr.Size)
+	}
+	return &m, sr.AccError()
+}
+
+// decodeMoovChildSR - decode a moov child box. A trak box is decoded from a copy of its data,
+// so that a decode error can be recorded in m.TrakErrs and the box kept as an UnknownBox.
+func decodeMoovChildSR(m *MoovBox, pos uint64, sr bits.SliceReader) (Box, error) {
+	boxStart := sr.GetPos()
+	h, err := DecodeHeaderSR(sr)
 	if err != nil {
 		return nil, err
 	}
-	m := MoovBox{Children: make([]Box, 0, len(children))}
-	m.StartPos = startPos
-	for _, c := range children {
-		m.AddChild(c)
+	sr.SetPos(boxStart)
+	if h.Name != "trak" || h.Size > uint64(sr.NrRemainingBytes()) {
+		return DecodeBoxSR(pos, sr)
 	}
-	return &m, err
+	data := sr.ReadBytes(int(h.Size))
+	trak, err := DecodeBoxSR(pos, bits.NewFixedSliceReader(data))
+	if err == nil {
+		return trak, nil
+	}
+	m.TrakErrs = append(m.TrakErrs, err)
+	return CreateUnknownBox(h.Name, h.Size, data[h.Hdrlen:]), sr.AccError()
 }
 
 // Type - box type
@@ -145,6 +172,16 @@ func (m *MoovBox) RemovePsshs() []*PsshBox {
 	return psshs
 }
 
+// GetTrak - get trak box with trackID in its tkhd box
+func (m *MoovBox) GetTrak(trackID uint32) (trak *TrakBox, ok bool) {
+	for _, trak := range m.Traks {
+		if trak.Tkhd.TrackID == trackID {
+			return trak, true
+		}
+	}
+	return nil, false
+}
+
 func (m *MoovBox) GetSinf(trackID uint32) *SinfBox {
 	for _, trak := range m.Traks {
 		if trak.Tkhd.TrackID == trackID {
@@ -178,3 +215,45 @@ func (m *MoovBox) IsEncrypted(trackID uint32) bool {
 	return false
 
 }
+
+// TrackInfo - summary of a track in a moov box
+type TrackInfo struct {
+	TrackID     uint32
+	HandlerType string      // e.g. vide, soun, text, subt
+	Codec       string      // type of first sample entry, or original format for encrypted tracks
+	Timescale   uint32      // mdhd timescale
+	Duration    uint64      // mdhd duration in timescale, 0 for fragmented files
+	CodecFamily CodecFamily // video codec family, CodecFamilyUnknown for other tracks
+	Supported   bool        // video codec family known and decoder configuration box present
+}
+
+// TrackInfos - summary of all tracks in order of appearance
+func (m *MoovBox) TrackInfos() []TrackInfo {
+	infos := make([]TrackInfo, 0, len(m.Traks))
+	for _, trak := range m.Traks {
+		ti := TrackInfo{
+			TrackID:     trak.Tkhd.TrackID,
+			HandlerType: trak.Mdia.Hdlr.HandlerType,
+			Timescale:   trak.Mdia.Mdhd.Timescale,
+			Duration:    trak.Mdia.Mdhd.Duration,
+		}
+		stsd := trak.Mdia.Minf.Stbl.Stsd
+		if len(stsd.Children) > 0 {
+			sd := stsd.Children[0]
+			ti.Codec = sd.Type()
+			var sinf *SinfBox
+			switch box := sd.(type) {
+			case *VisualSampleEntryBox:
+				sinf = box.Sinf
+				ti.CodecFamily, ti.Supported = GetCodecFamily(box)
+			case *AudioSampleEntryBox:
+				sinf = box.Sinf
+			}
+			if sinf != nil && sinf.Frma != nil {
+				ti.Codec = sinf.Frma.DataFormat
+			}
+		}
+		infos = append(infos, ti)
+	}
+	return infos
+}
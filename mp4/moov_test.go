@@ -0,0 +1,41 @@
+package mp4_test
+
+import (
+	"testing"
+
+	"github.com/Eyevinn/mp4ff/mp4"
+	"github.com/go-test/deep"
+)
+
+func TestMoovTrackInfos(t *testing.T) {
+	f, err := mp4.ReadMP4File("testdata/prog_8s.mp4")
+	if err != nil {
+		t.Fatal(err)
+	}
+	wanted := []mp4.TrackInfo{
+		{TrackID: 1, HandlerType: "soun", Codec: "mp4a", Timescale: 48000, Duration: 384000},
+		{TrackID: 2, HandlerType: "vide", Codec: "avc1", Timescale: 90000, Duration: 720000,
+			CodecFamily: mp4.CodecFamilyAvc, Supported: true},
+	}
+	got := f.Moov.TrackInfos()
+	if diff := deep.Equal(got, wanted); diff != nil {
+		t.Error(diff)
+	}
+}
+
+func TestMoovGetTrak(t *testing.T) {
+	f, err := mp4.ReadMP4File("testdata/prog_8s.mp4")
+	if err != nil {
+		t.Fatal(err)
+	}
+	trak, ok := f.Moov.GetTrak(2)
+	if !ok {
+		t.Fatal("trak with trackID 2 not found")
+	}
+	if trak.Mdia.Hdlr.HandlerType != "vide" {
+		t.Errorf("got handler type %q instead of vide", trak.Mdia.Hdlr.HandlerType)
+	}
+	if _, ok := f.Moov.GetTrak(3); ok {
+		t.Error("unexpected trak with trackID 3")
+	}
+}
@@ -0,0 +1,47 @@
+package mp4_test
+
+import (
+	"bytes"
+	"testing"
+
+	"github.com/Eyevinn/mp4ff/mp4"
+)
+
+func TestMvexWithTrepAndLeva(t *testing.T) {
+	mvex := mp4.NewMvexBox()
+	mvex.AddChild(mp4.CreateTrex(1))
+	mvex.AddChild(mp4.CreateTrex(2))
+	trep := &mp4.TrepBox{TrackID: 2}
+	trep.AddChild(&mp4.KindBox{SchemeURI: "X", Value: "Y"})
+	mvex.AddChild(trep)
+	leva := &mp4.LevaBox{}
+	for _, trackID := range []uint32{1, 2} {
+		lvl, err := mp4.NewLevaLevel(trackID, false, 2, 0, 0, 0)
+		if err != nil {
+			t.Fatal(err)
+		}
+		leva.Levels = append(leva.Levels, lvl)
+	}
+	mvex.AddChild(leva)
+	boxDiffAfterEncodeAndDecode(t, mvex)
+
+	buf := bytes.Buffer{}
+	err := mvex.Encode(&buf)
+	if err != nil {
+		t.Fatal(err)
+	}
+	box, err := mp4.DecodeBox(0, &buf)
+	if err != nil {
+		t.Fatal(err)
+	}
+	decMvex := box.(*mp4.MvexBox)
+	if decMvex.Leva == nil || len(decMvex.Leva.Levels) != 2 {
+		t.Errorf("expected leva box with 2 levels, got %v", decMvex.Leva)
+	}
+	if _, ok := decMvex.GetTrep(2); !ok {
+		t.Error("trep for track 2 not found")
+	}
+	if _, ok := decMvex.GetTrep(1); ok {
+		t.Error("unexpected trep for track 1")
+	}
+}
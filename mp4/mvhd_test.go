@@ -44,3 +44,25 @@ func TestMvhdTimeDecodeS(t *testing.T) {
 		t.Errorf("ModificationTimeS %d not 0", mvhd.ModificationTimeS())
 	}
 }
+
+func TestMvhdVersion1(t *testing.T) {
+	mvhd := mp4.CreateMvhd()
+	mvhd.Version = 1
+	mvhd.Timescale = 1000
+	mvhd.Duration = 1 << 33 // Does not fit in 32 bits
+	boxDiffAfterEncodeAndDecode(t, mvhd)
+
+	buf := bytes.Buffer{}
+	err := mvhd.Encode(&buf)
+	if err != nil {
+		t.Error(err)
+	}
+	box, err := mp4.DecodeBox(0, &buf)
+	if err != nil {
+		t.Error(err)
+	}
+	decMvhd := box.(*mp4.MvhdBox)
+	if decMvhd.Duration != 1<<33 {
+		t.Errorf("got duration %d instead of %d", decMvhd.Duration, uint64(1<<33))
+	}
+}
@@ -0,0 +1,141 @@
+package mp4
+
+import (
+	"bytes"
+	"fmt"
+	"math"
+)
+
+// CreateProgressiveFile - create a progressive file with the single track of init and samples with data.
+// The init segment, e.g. from CreateEmptyInit, AddEmptyTrack, and SetAVCDescriptor, or from
+// CreateInitFromProgressive, provides the track and its sample description.
+// The file gets an isom ftyp box and a copy of the moov box of init, so init is not changed.
+// In the copy, the mvex box is removed, the sample tables and durations are filled in, and the sample data
+// is put as one chunk in an mdat box after the moov box.
+func CreateProgressiveFile(init *InitSegment, samples []FullSample) (*File, error) {
+	if len(init.Moov.Traks) != 1 {
+		return nil, fmt.Errorf("init segment has %d tracks instead of 1", len(init.Moov.Traks))
+	}
+	if len(samples) == 0 {
+		return nil, fmt.Errorf("no samples")
+	}
+	if init.Moov.Trak.Mdia.Mdhd.Timescale == 0 {
+		return nil, fmt.Errorf("mdhd timescale is 0")
+	}
+	moov, err := copyMoov(init.Moov)
+	if err != nil {
+		return nil, err
+	}
+	trak := moov.Trak
+	stbl := trak.Mdia.Minf.Stbl
+	if stbl.Stts == nil || stbl.Stsc == nil || stbl.Stsz == nil || stbl.Stco == nil {
+		return nil, fmt.Errorf("stts, stsc, stsz, or stco missing in init segment")
+	}
+	if len(stbl.Stts.SampleCount) != 0 || stbl.Stsz.SampleNumber != 0 {
+		return nil, fmt.Errorf("sample tables in init segment are not empty")
+	}
+	if moov.Mvex != nil {
+		for i, child := range moov.Children {
+			if child == moov.Mvex {
+				moov.Children = append(moov.Children[:i], moov.Children[i+1:]...)
+				break
+			}
+		}
+		moov.Mvex = nil
+	}
+
+	mdat := &MdatBox{}
+	stts := stbl.Stts
+	stsz := stbl.Stsz
+	var syncSampleNrs []uint32
+	var cttsCounts []uint32
+	var cttsOffsets []int32
+	hasCompositionTimeOffsets := false
+	var totDur uint64
+	for i, s := range samples {
+		if len(s.Data) != int(s.Size) {
+			return nil, fmt.Errorf("sample %d: size %d but %d bytes of data", i+1, s.Size, len(s.Data))
+		}
+		mdat.AddSampleData(s.Data)
+		stsz.SampleSize = append(stsz.SampleSize, s.Size)
+		totDur += uint64(s.Dur)
+		if n := len(stts.SampleCount); n > 0 && stts.SampleTimeDelta[n-1] == s.Dur {
+			stts.SampleCount[n-1]++
+		} else {
+			stts.SampleCount = append(stts.SampleCount, 1)
+			stts.SampleTimeDelta = append(stts.SampleTimeDelta, s.Dur)
+		}
+		if !DecodeSampleFlags(s.Flags).SampleIsNonSync {
+			syncSampleNrs = append(syncSampleNrs, uint32(i+1))
+		}
+		if s.CompositionTimeOffset != 0 {
+			hasCompositionTimeOffsets = true
+		}
+		if n := len(cttsCounts); n > 0 && cttsOffsets[n-1] == s.CompositionTimeOffset {
+			cttsCounts[n-1]++
+		} else {
+			cttsCounts = append(cttsCounts, 1)
+			cttsOffsets = append(cttsOffsets, s.CompositionTimeOffset)
+		}
+	}
+	stsz.SampleNumber = uint32(len(samples))
+	if len(syncSampleNrs) < len(samples) { // stss is only needed if some sample is non-sync
+		stbl.AddChild(&StssBox{SampleNumber: syncSampleNrs})
+	}
+	if hasCompositionTimeOffsets {
+		ctts := &CttsBox{}
+		for _, offset := range cttsOffsets {
+			if offset < 0 {
+				ctts.Version = 1
+			}
+		}
+		if err := ctts.AddSampleCountsAndOffset(cttsCounts, cttsOffsets); err != nil {
+			return nil, err
+		}
+		stbl.AddChild(ctts)
+	}
+	if err := stbl.Stsc.AddEntry(1, uint32(len(samples)), 1); err != nil {
+		return nil, err
+	}
+
+	mdhd := trak.Mdia.Mdhd
+	mdhd.Duration = totDur
+	if totDur > math.MaxUint32 {
+		mdhd.Version = 1
+	}
+	movieDur := totDur * uint64(moov.Mvhd.Timescale) / uint64(mdhd.Timescale)
+	moov.Mvhd.Duration = movieDur
+	trak.Tkhd.Duration = movieDur
+	if movieDur > math.MaxUint32 {
+		moov.Mvhd.Version = 1
+		trak.Tkhd.Version = 1
+	}
+
+	// The chunk offset does not change the moov size, so it can be set after adding a placeholder
+	stbl.Stco.ChunkOffset = []uint32{0}
+	ftyp := NewFtyp("isom", 0x200, []string{"isom", "iso2", "mp41"})
+	chunkOffset := ftyp.Size() + moov.Size() + mdat.HeaderSize()
+	if chunkOffset > math.MaxUint32 {
+		return nil, fmt.Errorf("chunk offset %d does not fit in stco", chunkOffset)
+	}
+	stbl.Stco.ChunkOffset[0] = uint32(chunkOffset)
+
+	f := NewFile()
+	f.AddChild(ftyp, 0)
+	f.AddChild(moov, ftyp.Size())
+	f.AddChild(mdat, ftyp.Size()+moov.Size())
+	return f, nil
+}
+
+// copyMoov - make a deep copy of moov by encoding and decoding it
+func copyMoov(moov *MoovBox) (*MoovBox, error) {
+	buf := bytes.Buffer{}
+	if err := moov.Encode(&buf); err != nil {
+		return nil, err
+	}
+	box, err := DecodeBox(0, &buf)
+	if err != nil {
+		return nil, err
+	}
+	return box.(*MoovBox), nil
+}
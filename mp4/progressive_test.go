@@ -0,0 +1,113 @@
+package mp4_test
+
+import (
+	"bytes"
+	"testing"
+
+	"github.com/Eyevinn/mp4ff/mp4"
+)
+
+// readFullSamples - read all samples of a track in a progressive file including data
+func readFullSamples(t *testing.T, f *mp4.File, trak *mp4.TrakBox) ([]mp4.Sample, []mp4.FullSample) {
+	t.Helper()
+	nrSamples := trak.GetNrSamples()
+	samples, err := trak.GetSampleData(1, nrSamples)
+	if err != nil {
+		t.Fatal(err)
+	}
+	ranges, err := trak.GetSampleRanges(1, nrSamples)
+	if err != nil {
+		t.Fatal(err)
+	}
+	fullSamples := make([]mp4.FullSample, 0, nrSamples)
+	var decodeTime uint64
+	for i, s := range samples {
+		start := ranges[i].Offset - f.Mdat.PayloadAbsoluteOffset()
+		data := f.Mdat.Data[start : start+ranges[i].Size]
+		fullSamples = append(fullSamples, mp4.FullSample{Sample: s, DecodeTime: decodeTime, Data: data})
+		decodeTime += uint64(s.Dur)
+	}
+	return samples, fullSamples
+}
+
+func TestCreateProgressiveFile(t *testing.T) {
+	progFile, err := mp4.ReadMP4File("testdata/prog_8s.mp4")
+	if err != nil {
+		t.Fatal(err)
+	}
+	inTrak, _ := progFile.Moov.GetTrak(2)
+	inSamples, fullSamples := readFullSamples(t, progFile, inTrak)
+
+	init, err := mp4.CreateInitFromProgressive(progFile.Moov, 2)
+	if err != nil {
+		t.Fatal(err)
+	}
+	_, err = mp4.CreateProgressiveFile(init, nil)
+	if err == nil {
+		t.Error("expected error for no samples")
+	}
+	f, err := mp4.CreateProgressiveFile(init, fullSamples)
+	if err != nil {
+		t.Fatal(err)
+	}
+	initStbl := init.Moov.Trak.Mdia.Minf.Stbl
+	if init.Moov.Mvex == nil || len(initStbl.Stts.SampleCount) != 0 || initStbl.Stsz.SampleNumber != 0 {
+		t.Error("init segment was changed")
+	}
+	if _, err = mp4.CreateProgressiveFile(init, fullSamples); err != nil {
+		t.Errorf("second file from same init segment: %s", err)
+	}
+	buf := bytes.Buffer{}
+	err = f.Encode(&buf)
+	if err != nil {
+		t.Fatal(err)
+	}
+	decFile, err := mp4.DecodeFile(&buf)
+	if err != nil {
+		t.Fatal(err)
+	}
+	if decFile.IsFragmented() || decFile.Moov.Mvex != nil {
+		t.Error("created file is fragmented")
+	}
+	if mb := decFile.Ftyp.MajorBrand(); mb != "isom" {
+		t.Errorf("got major brand %q instead of isom", mb)
+	}
+	outTrak := decFile.Moov.Trak
+	if outTrak.Mdia.Mdhd.Duration != inTrak.Mdia.Mdhd.Duration {
+		t.Errorf("got mdhd duration %d instead of %d", outTrak.Mdia.Mdhd.Duration, inTrak.Mdia.Mdhd.Duration)
+	}
+	outStbl := outTrak.Mdia.Minf.Stbl
+	if outStbl.Stss == nil || outStbl.Ctts == nil {
+		t.Error("stss or ctts missing in created file")
+	}
+	outSamples, outFullSamples := readFullSamples(t, decFile, outTrak)
+	if len(outSamples) != len(inSamples) {
+		t.Fatalf("got %d samples instead of %d", len(outSamples), len(inSamples))
+	}
+	for i := range outSamples {
+		out, in := &outSamples[i], &inSamples[i]
+		// The flags only keep sync information, since there is no sdtp box in the created file
+		if isNonSync(out) != isNonSync(in) || out.Dur != in.Dur || out.Size != in.Size ||
+			out.CompositionTimeOffset != in.CompositionTimeOffset {
+			t.Errorf("sample %d: got %+v instead of %+v", i+1, *out, *in)
+			break
+		}
+		if !bytes.Equal(outFullSamples[i].Data, fullSamples[i].Data) {
+			t.Errorf("sample %d: data differs", i+1)
+			break
+		}
+	}
+}
+
+func TestCreateProgressiveFileZeroTimescale(t *testing.T) {
+	init := mp4.CreateEmptyInit()
+	init.AddEmptyTrack(0, "video", "und")
+	samples := []mp4.FullSample{{Sample: mp4.NewSample(mp4.SyncSampleFlags, 1, 1, 0), Data: []byte{0}}}
+	if _, err := mp4.CreateProgressiveFile(init, samples); err == nil {
+		t.Error("expected error for mdhd timescale 0")
+	}
+}
+
+func isNonSync(s *mp4.Sample) bool {
+	return mp4.DecodeSampleFlags(s.Flags).SampleIsNonSync
+}
@@ -1,6 +1,7 @@
 package mp4
 
 import (
+	"fmt"
 	"io"
 
 	"github.com/Eyevinn/mp4ff/bits"
@@ -135,3 +136,48 @@ func (b *StblBox) EncodeSW(sw bits.SliceWriter) error {
 func (s *StblBox) Info(w io.Writer, specificBoxLevels, indent, indentStep string) error {
 	return ContainerInfo(s, w, specificBoxLevels, indent, indentStep)
 }
+
+// SampleCountMismatchError - a sample table box covers a different number of samples than stsz
+type SampleCountMismatchError struct {
+	BoxType  string // Type of the box with the mismatching count
+	Expected uint32 // Number of samples according to stsz
+	Got      uint32 // Number of samples according to BoxType
+}
+
+func (e *SampleCountMismatchError) Error() string {
+	return fmt.Sprintf("%s has %d samples, but stsz has %d", e.BoxType, e.Got, e.Expected)
+}
+
+// CheckSampleCounts - check that stts and ctts cover the same number of samples as stsz,
+// and that stss only refers to existing samples.
+// sdtp is not checked, since it only provides optional sample dependency flags.
+// A count mismatch is returned as a *SampleCountMismatchError.
+func (s *StblBox) CheckSampleCounts() error {
+	if s.Stsz == nil {
+		return fmt.Errorf("stbl: no stsz box")
+	}
+	nrSamples := s.Stsz.GetNrSamples()
+	if s.Stts != nil {
+		var count uint32
+		for _, c := range s.Stts.SampleCount {
+			count += c
+		}
+		if count != nrSamples {
+			return &SampleCountMismatchError{BoxType: "stts", Expected: nrSamples, Got: count}
+		}
+	}
+	if s.Ctts != nil && len(s.Ctts.EndSampleNr) > 0 {
+		count := s.Ctts.EndSampleNr[len(s.Ctts.EndSampleNr)-1]
+		if count != nrSamples {
+			return &SampleCountMismatchError{BoxType: "ctts", Expected: nrSamples, Got: count}
+		}
+	}
+	if s.Stss != nil {
+		for _, nr := range s.Stss.SampleNumber {
+			if nr == 0 || nr > nrSamples {
+				return fmt.Errorf("stss: sync sample %d outside range 1-%d", nr, nrSamples)
+			}
+		}
+	}
+	return nil
+}
@@ -0,0 +1,55 @@
+package mp4_test
+
+import (
+	"errors"
+	"os"
+	"testing"
+
+	"github.com/Eyevinn/mp4ff/mp4"
+)
+
+func TestStblCheckSampleCounts(t *testing.T) {
+	f, err := os.Open("testdata/bbb_prog_10s.mp4")
+	if err != nil {
+		t.Fatal(err)
+	}
+	defer f.Close()
+	mf, err := mp4.DecodeFile(f)
+	if err != nil {
+		t.Fatal(err)
+	}
+	for _, trak := range mf.Moov.Traks {
+		err := trak.Mdia.Minf.Stbl.CheckSampleCounts()
+		if err != nil {
+			t.Errorf("track %d: %s", trak.Tkhd.TrackID, err)
+		}
+	}
+	stbl := mf.Moov.Traks[0].Mdia.Minf.Stbl
+	stbl.AddChild(mp4.CreateSdtpBox([]mp4.SdtpEntry{mp4.NewSdtpEntry(0, 2, 0, 0)}))
+	if err := stbl.CheckSampleCounts(); err != nil {
+		t.Errorf("sdtp with too few entries: %s", err)
+	}
+	ctts := &mp4.CttsBox{}
+	err = ctts.AddSampleCountsAndOffset([]uint32{1}, []int32{0})
+	if err != nil {
+		t.Fatal(err)
+	}
+	stbl.Ctts = ctts
+	err = stbl.CheckSampleCounts()
+	var mismatch *mp4.SampleCountMismatchError
+	if !errors.As(err, &mismatch) {
+		t.Fatalf("expected SampleCountMismatchError, got %v", err)
+	}
+	if mismatch.BoxType != "ctts" || mismatch.Got != 1 || mismatch.Expected != stbl.Stsz.GetNrSamples() {
+		t.Errorf("unexpected mismatch %+v", *mismatch)
+	}
+	trak := mf.Moov.Traks[0]
+	_, err = trak.GetSampleRanges(1, trak.GetNrSamples())
+	if !errors.As(err, &mismatch) || mismatch.BoxType != "ctts" {
+		t.Errorf("GetSampleRanges: expected ctts SampleCountMismatchError, got %v", err)
+	}
+	_, err = trak.GetSampleData(1, trak.GetNrSamples())
+	if !errors.As(err, &mismatch) || mismatch.BoxType != "ctts" {
+		t.Errorf("GetSampleData: expected ctts SampleCountMismatchError, got %v", err)
+	}
+}
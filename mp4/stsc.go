@@ -24,6 +24,7 @@ type StscBox struct {
 	singleSampleDescriptionID uint32 // Used instead of slice if all values are the same
 	Entries                   []StscEntry
 	SampleDescriptionID       []uint32
+	unsortedErr               error // Set if decoded first chunk values are not increasing
 }
 
 type StscEntry struct {
@@ -62,7 +63,15 @@ func DecodeStscSR(hdr BoxHeader, startPos uint64, sr bits.SliceReader) (Box, err
 		b.Entries[i].FirstChunk = sr.ReadUint32()
 		b.Entries[i].SamplesPerChunk = sr.ReadUint32()
 		if i > 0 {
-			accSampleNr += (b.Entries[i].FirstChunk - b.Entries[i-1].FirstChunk) * b.Entries[i-1].SamplesPerChunk
+			// Unsorted entries are kept, so that the box can be listed and re-encoded, but lookups fail
+			if b.Entries[i].FirstChunk <= b.Entries[i-1].FirstChunk {
+				if b.unsortedErr == nil {
+					b.unsortedErr = fmt.Errorf("stsc entries not sorted: first chunk %d after %d",
+						b.Entries[i].FirstChunk, b.Entries[i-1].FirstChunk)
+				}
+			} else {
+				accSampleNr += (b.Entries[i].FirstChunk - b.Entries[i-1].FirstChunk) * b.Entries[i-1].SamplesPerChunk
+			}
 		}
 		b.Entries[i].FirstSampleNr = accSampleNr
 
@@ -161,6 +170,10 @@ func (b *StscBox) AddEntry(firstChunk, samplesPerChunk, sampleDescriptionID uint
 		b.singleSampleDescriptionID = sampleDescriptionID
 	default:
 		nrEntries := len(b.Entries)
+		lastEntry := b.Entries[nrEntries-1]
+		if firstChunk <= lastEntry.FirstChunk {
+			return fmt.Errorf("stsc firstChunk %d not larger than previous %d", firstChunk, lastEntry.FirstChunk)
+		}
 		if sampleDescriptionID != b.singleSampleDescriptionID {
 			if b.singleSampleDescriptionID != 0 {
 				b.SampleDescriptionID = make([]uint32, nrEntries)
@@ -171,7 +184,6 @@ func (b *StscBox) AddEntry(firstChunk, samplesPerChunk, sampleDescriptionID uint
 			}
 			b.SampleDescriptionID = append(b.SampleDescriptionID, sampleDescriptionID)
 		}
-		lastEntry := b.Entries[len(b.Entries)-1]
 		firstSampleNr := lastEntry.FirstSampleNr + (firstChunk-lastEntry.FirstChunk)*lastEntry.SamplesPerChunk
 		b.Entries = append(b.Entries, StscEntry{firstChunk, samplesPerChunk, firstSampleNr})
 	}
@@ -195,7 +207,13 @@ func (b *StscBox) SetSingleSampleDescriptionID(sampleDescriptionID uint32) {
 
 // ChunkNrFromSampleNr - get chunk number from sampleNr (one-based)
 func (b *StscBox) ChunkNrFromSampleNr(sampleNr int) (chunkNr, firstSampleInChunk int, err error) {
+	if sampleNr <= 0 {
+		return 0, 0, fmt.Errorf("sampleNr %d is not one-based", sampleNr)
+	}
 	entryNr := b.FindEntryNrForSampleNr(uint32(sampleNr), 0)
+	if err := b.checkEntryNr(entryNr); err != nil {
+		return 0, 0, err
+	}
 	entry := b.Entries[entryNr]
 	nrInEntry := (uint32(sampleNr) - entry.FirstSampleNr) / entry.SamplesPerChunk
 	chunkNr = int(entry.FirstChunk + nrInEntry)
@@ -219,7 +237,13 @@ func (b *StscBox) GetContainingChunks(startSampleNr, endSampleNr uint32) ([]Chun
 	nrEntries := uint32(len(b.Entries))
 
 	startEntryNr := b.FindEntryNrForSampleNr(startSampleNr, 0)
+	if err := b.checkEntryNr(startEntryNr); err != nil {
+		return nil, err
+	}
 	endEntryNr := b.FindEntryNrForSampleNr(endSampleNr, startEntryNr)
+	if err := b.checkEntryNr(endEntryNr); err != nil {
+		return nil, err
+	}
 
 	startEntry := b.Entries[startEntryNr]
 	endEntry := b.Entries[endEntryNr]
@@ -243,6 +267,21 @@ func (b *StscBox) GetContainingChunks(startSampleNr, endSampleNr uint32) ([]Chun
 	return chunks, nil
 }
 
+// checkEntryNr - check that entryNr is a valid entry index with samples that can be used for lookup,
+// and that the entries are sorted by first chunk
+func (b *StscBox) checkEntryNr(entryNr uint32) error {
+	if b.unsortedErr != nil {
+		return b.unsortedErr
+	}
+	if entryNr >= uint32(len(b.Entries)) {
+		return fmt.Errorf("no stsc entry for sample (%d entries)", len(b.Entries))
+	}
+	if b.Entries[entryNr].SamplesPerChunk == 0 {
+		return fmt.Errorf("stsc entry %d has samplesPerChunk 0", entryNr+1)
+	}
+	return nil
+}
+
 // GetChunk returns chunk for chunkNr (one-based).
 func (b *StscBox) GetChunk(chunkNr uint32) Chunk {
 	if chunkNr == 0 {
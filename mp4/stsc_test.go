@@ -4,6 +4,7 @@ import (
 	"bytes"
 	"testing"
 
+	"github.com/Eyevinn/mp4ff/bits"
 	"github.com/Eyevinn/mp4ff/mp4"
 	"github.com/go-test/deep"
 )
@@ -191,3 +192,62 @@ func TestBadSizeStsc(t *testing.T) {
 		t.Error("expected invalid size error")
 	}
 }
+
+func TestUnsortedStsc(t *testing.T) {
+	// raw stsc box with entries for first chunks 1, 3, 2
+	raw := []byte{0x00, 0x00, 0x00, 0x34, 's', 't', 's', 'c', 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x03,
+		0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x0a, 0x00, 0x00, 0x00, 0x01,
+		0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x05, 0x00, 0x00, 0x00, 0x01,
+		0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x01}
+	for _, useSR := range []bool{false, true} {
+		var decoded mp4.Box
+		var err error
+		if useSR {
+			decoded, err = mp4.DecodeBoxSR(0, bits.NewFixedSliceReader(raw))
+		} else {
+			decoded, err = mp4.DecodeBox(0, bytes.NewBuffer(raw))
+		}
+		if err != nil {
+			t.Fatalf("unsorted stsc entries should decode: %s", err)
+		}
+		stsc := decoded.(*mp4.StscBox)
+		out := bytes.Buffer{}
+		if err = stsc.Encode(&out); err != nil {
+			t.Fatal(err)
+		}
+		if !bytes.Equal(out.Bytes(), raw) {
+			t.Error("encoded stsc differs from input")
+		}
+		if _, _, err = stsc.ChunkNrFromSampleNr(12); err == nil {
+			t.Error("expected error for chunk lookup with unsorted stsc entries")
+		}
+		if _, err = stsc.GetContainingChunks(1, 12); err == nil {
+			t.Error("expected error for chunks lookup with unsorted stsc entries")
+		}
+	}
+	box := mp4.StscBox{}
+	_ = box.AddEntry(1, 10, 1)
+	_ = box.AddEntry(3, 5, 1)
+	err := box.AddEntry(2, 4, 1)
+	if err == nil {
+		t.Error("expected error when adding unsorted stsc entry")
+	}
+}
+
+func TestStscBadLookups(t *testing.T) {
+	empty := &mp4.StscBox{}
+	if _, _, err := empty.ChunkNrFromSampleNr(1); err == nil {
+		t.Error("expected error for chunk lookup without entries")
+	}
+	if _, err := empty.GetContainingChunks(1, 2); err == nil {
+		t.Error("expected error for containing chunks without entries")
+	}
+	zero := &mp4.StscBox{}
+	_ = zero.AddEntry(1, 0, 1)
+	if _, _, err := zero.ChunkNrFromSampleNr(1); err == nil {
+		t.Error("expected error for samplesPerChunk 0")
+	}
+	if _, err := zero.GetContainingChunks(1, 2); err == nil {
+		t.Error("expected error for samplesPerChunk 0")
+	}
+}
@@ -18,7 +18,7 @@ type StsdBox struct {
 	SampleCount uint32
 	// AvcX is a pointer to box with name avc1 or avc3
 	AvcX *VisualSampleEntryBox
-	// HvcX is a pointer to a box with name hvc1 or hev1
+	// HvcX is a pointer to a box with name hvc1 or hev1, or the Dolby Vision variants dvh1 or dvhe
 	HvcX *VisualSampleEntryBox
 	// Av01 is a pointer to a box with name av01
 	Av01 *VisualSampleEntryBox
@@ -40,6 +40,8 @@ type StsdBox struct {
 	Wvtt *WvttBox
 	// Stpp is a pointer to a StppBox
 	Stpp *StppBox
+	// Tx3g is a pointer to a Tx3gBox
+	Tx3g *Tx3gBox
 	// Evte is a pointer to an EvteBox
 	Evte     *EvteBox
 	Children []Box
@@ -55,7 +57,7 @@ func (s *StsdBox) AddChild(box Box) {
 	switch box.Type() {
 	case "avc1", "avc3":
 		s.AvcX = box.(*VisualSampleEntryBox)
-	case "hvc1", "hev1":
+	case "hvc1", "hev1", "dvh1", "dvhe":
 		s.HvcX = box.(*VisualSampleEntryBox)
 	case "encv":
 		s.Encv = box.(*VisualSampleEntryBox)
@@ -77,6 +79,8 @@ func (s *StsdBox) AddChild(box Box) {
 		s.Wvtt = box.(*WvttBox)
 	case "stpp":
 		s.Stpp = box.(*StppBox)
+	case "tx3g":
+		s.Tx3g = box.(*Tx3gBox)
 	case "evte":
 		s.Evte = box.(*EvteBox)
 	}
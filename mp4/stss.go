@@ -87,6 +87,25 @@ func (b *StssBox) IsSyncSample(sampleNr uint32) (isSync bool) {
 	return i < nrSamples && b.SampleNumber[i] == sampleNr
 }
 
+// SyncSampleNrAtOrBefore - get the last sync sample number (one-based) that is not after sampleNr.
+// ok is false if there is no such sync sample.
+func (b *StssBox) SyncSampleNrAtOrBefore(sampleNr uint32) (syncNr uint32, ok bool) {
+	// i will be the lowest index such that b.SampleNumber[i] > sampleNr
+	i, j := 0, len(b.SampleNumber)
+	for i < j {
+		h := (i + j) >> 1
+		if b.SampleNumber[h] <= sampleNr {
+			i = h + 1
+		} else {
+			j = h
+		}
+	}
+	if i == 0 {
+		return 0, false
+	}
+	return b.SampleNumber[i-1], true
+}
+
 // Encode - write box to w
 func (b *StssBox) Encode(w io.Writer) error {
 	sw := bits.NewFixedSliceWriter(int(b.Size()))
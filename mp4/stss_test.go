@@ -74,3 +74,18 @@ func TestStssNoSamples(t *testing.T) {
 		}
 	}
 }
+
+func TestStssSyncSampleNrAtOrBefore(t *testing.T) {
+	stss := &mp4.StssBox{
+		SampleNumber: []uint32{5, 26},
+	}
+	if _, ok := stss.SyncSampleNrAtOrBefore(4); ok {
+		t.Error("got sync sample before first sync sample 5")
+	}
+	for sampleNr, wanted := range map[uint32]uint32{5: 5, 25: 5, 26: 26, 30: 26} {
+		syncNr, ok := stss.SyncSampleNrAtOrBefore(sampleNr)
+		if !ok || syncNr != wanted {
+			t.Errorf("sample %d: got sync sample %d instead of %d", sampleNr, syncNr, wanted)
+		}
+	}
+}
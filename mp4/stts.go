@@ -90,7 +90,8 @@ func (b *SttsBox) GetTimeCode(sample, timescale uint32) time.Duration {
 	return time.Second * time.Duration(units) / time.Duration(timescale)
 }
 
-// GetDecodeTime - decode time and duration for (one-based) sampleNr in track timescale
+// GetDecodeTime - decode time and duration for (one-based) sampleNr in track timescale.
+// Samples after the last entry get the duration of the last entry.
 func (b *SttsBox) GetDecodeTime(sampleNr uint32) (decTime uint64, dur uint32) {
 	if sampleNr == 0 {
 		// This is bad index input. Should never happen
@@ -100,6 +101,10 @@ func (b *SttsBox) GetDecodeTime(sampleNr uint32) (decTime uint64, dur uint32) {
 	decTime = 0
 	i := 0
 	for {
+		if i == len(b.SampleCount) {
+			decTime += uint64(samplesRemaining) * uint64(dur)
+			break
+		}
 		dur = b.SampleTimeDelta[i]
 		if samplesRemaining >= b.SampleCount[i] {
 			decTime += uint64(b.SampleCount[i]) * uint64(dur)
@@ -115,6 +120,15 @@ func (b *SttsBox) GetDecodeTime(sampleNr uint32) (decTime uint64, dur uint32) {
 	return decTime, dur
 }
 
+// TotalDuration - sum of all sample durations
+func (b *SttsBox) TotalDuration() uint64 {
+	var totDur uint64
+	for i, count := range b.SampleCount {
+		totDur += uint64(count) * uint64(b.SampleTimeDelta[i])
+	}
+	return totDur
+}
+
 // GetDur - get dur for a specific sample
 func (b *SttsBox) GetDur(sampleNr uint32) (dur uint32) {
 	if sampleNr == 0 {
@@ -201,7 +215,7 @@ func (b *SttsBox) GetSampleNrAtTime(sampleStartTime uint64) (sampleNr uint32, er
 		accTime += timeDelta * uint64(b.SampleCount[i])
 	}
 	// Check if there is a final single zero duration and time matches.
-	if b.SampleTimeDelta[nrEntries-1] == 0 && b.SampleCount[nrEntries-1] == 1 &&
+	if nrEntries > 0 && b.SampleTimeDelta[nrEntries-1] == 0 && b.SampleCount[nrEntries-1] == 1 &&
 		sampleStartTime == accTime {
 		return accNr, nil
 	}
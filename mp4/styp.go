@@ -2,6 +2,7 @@ package mp4
 
 import (
 	"encoding/binary"
+	"fmt"
 	"io"
 
 	"github.com/Eyevinn/mp4ff/bits"
@@ -62,6 +63,9 @@ func NewStyp(majorBrand string, minorVersion uint32, compatibleBrands []string)
 
 // DecodeStyp - box-specific decode
 func DecodeStyp(hdr BoxHeader, startPos uint64, r io.Reader) (Box, error) {
+	if hdr.payloadLen() < 8 {
+		return nil, fmt.Errorf("styp payload size %d less than 8", hdr.payloadLen())
+	}
 	data, err := readBoxBody(r, hdr)
 	if err != nil {
 		return nil, err
@@ -72,7 +76,10 @@ func DecodeStyp(hdr BoxHeader, startPos uint64, r io.Reader) (Box, error) {
 
 // DecodeStypSR - box-specific decode
 func DecodeStypSR(hdr BoxHeader, startPos uint64, sr bits.SliceReader) (Box, error) {
-	b := StypBox{data: sr.ReadBytes(int(hdr.Size) - hdr.Hdrlen)}
+	if hdr.payloadLen() < 8 {
+		return nil, fmt.Errorf("styp payload size %d less than 8", hdr.payloadLen())
+	}
+	b := StypBox{data: sr.ReadBytes(hdr.payloadLen())}
 	return &b, sr.AccError()
 }
 
@@ -14,6 +14,7 @@ import (
 // Volume (relevant for audio tracks) is a fixed point number (8 bits + 8 bits). Full volume is 1.0.
 // Width and Height (relevant for video tracks) are fixed point numbers (16 bits + 16 bits).
 // Video pixels are not necessarily square.
+// Matrix is the transformation matrix {a, b, u, c, d, v, x, y, w} for video, and nil for the unity matrix.
 type TkhdBox struct {
 	Version          byte
 	Flags            uint32
@@ -24,6 +25,7 @@ type TkhdBox struct {
 	Layer            int16
 	AlternateGroup   int16 // should be int16
 	Volume           Fixed16
+	Matrix           []int32
 	Width, Height    Fixed32
 }
 
@@ -75,7 +77,13 @@ func DecodeTkhdSR(hdr BoxHeader, startPos uint64, sr bits.SliceReader) (Box, err
 	t.AlternateGroup = sr.ReadInt16()
 	t.Volume = Fixed16(sr.ReadInt16())
 	sr.SkipBytes(2)
-	sr.SkipBytes(36) // 3x3 matrixdata
+	matrix := make([]int32, 9)
+	for i := range matrix {
+		matrix[i] = sr.ReadInt32()
+	}
+	if !isUnityMatrix(matrix) {
+		t.Matrix = matrix
+	}
 	t.Width = Fixed32(sr.ReadUint32())
 	t.Height = Fixed32(sr.ReadUint32())
 
@@ -131,8 +139,14 @@ func (b *TkhdBox) EncodeSW(sw bits.SliceWriter) error {
 	sw.WriteInt16(b.Layer)
 	sw.WriteInt16(b.AlternateGroup)
 	sw.WriteUint16(uint16(b.Volume))
-	sw.WriteZeroBytes(2)  // Reserved
-	sw.WriteUnityMatrix() // unity matrix according to 8.3.2.2
+	sw.WriteZeroBytes(2) // Reserved
+	if len(b.Matrix) == 9 {
+		for _, m := range b.Matrix {
+			sw.WriteInt32(m)
+		}
+	} else {
+		sw.WriteUnityMatrix() // unity matrix according to 8.3.2.2
+	}
 	sw.WriteUint32(uint32(b.Width))
 	sw.WriteUint32(uint32(b.Height))
 
@@ -149,9 +163,52 @@ func (b *TkhdBox) Info(w io.Writer, specificBoxLevels, indent, indentStep string
 	if b.Width != 0 && b.Height != 0 { // These are Fixed32 values
 		bd.write(" - Width: %s, Height: %s", b.Width, b.Height)
 	}
+	if b.Matrix != nil {
+		bd.write(" - matrix: %v", b.Matrix)
+		if degrees, ok := b.Rotation(); ok {
+			bd.write(" - rotation: %d", degrees)
+		}
+	}
 	return bd.err
 }
 
+// Rotation - clockwise display rotation in degrees (0, 90, 180, or 270) given by Matrix.
+// ok is false if the matrix is not a pure rotation by a multiple of 90 degrees.
+func (b *TkhdBox) Rotation() (degrees int, ok bool) {
+	if b.Matrix == nil {
+		return 0, true
+	}
+	if len(b.Matrix) != 9 {
+		return 0, false
+	}
+	const one = 0x00010000 // 1 in fixed 16.16
+	m := b.Matrix
+	if m[2] != 0 || m[5] != 0 || m[8] != 0x40000000 {
+		return 0, false
+	}
+	switch [4]int32{m[0], m[1], m[3], m[4]} {
+	case [4]int32{one, 0, 0, one}:
+		return 0, true
+	case [4]int32{0, one, -one, 0}:
+		return 90, true
+	case [4]int32{-one, 0, 0, -one}:
+		return 180, true
+	case [4]int32{0, -one, one, 0}:
+		return 270, true
+	}
+	return 0, false
+}
+
+func isUnityMatrix(m []int32) bool {
+	unity := [9]int32{0x00010000, 0, 0, 0, 0x00010000, 0, 0, 0, 0x40000000}
+	for i := range unity {
+		if m[i] != unity[i] {
+			return false
+		}
+	}
+	return true
+}
+
 // CraetionTimeS returns the creation time in seconds since Jan 1, 1970
 func (b *TkhdBox) CreationTimeS() int64 {
 	return int64(b.CreationTime) - EpochDiffS
@@ -34,3 +34,30 @@ func TestTkhd(t *testing.T) {
 		t.Errorf("Mismatch mvhdCreated vs mvhdRead:\n%+v\n%+v", tkhdCreated, tkhdRead)
 	}
 }
+
+func TestTkhdRotation(t *testing.T) {
+	const one = 0x00010000
+	testCases := []struct {
+		version byte
+		matrix  []int32
+		degrees int
+		ok      bool
+	}{
+		{0, nil, 0, true},
+		{0, []int32{0, one, 0, -one, 0, 0, 0, 0, 0x40000000}, 90, true},
+		{1, []int32{0, one, 0, -one, 0, 0, 720 << 16, 0, 0x40000000}, 90, true},
+		{1, []int32{-one, 0, 0, 0, -one, 0, 1280 << 16, 720 << 16, 0x40000000}, 180, true},
+		{0, []int32{0, -one, 0, one, 0, 0, 0, 1280 << 16, 0x40000000}, 270, true},
+		{0, []int32{2 * one, 0, 0, 0, one, 0, 0, 0, 0x40000000}, 0, false},
+	}
+	for _, tc := range testCases {
+		tkhd := mp4.CreateTkhd()
+		tkhd.Version = tc.version
+		tkhd.Matrix = tc.matrix
+		boxDiffAfterEncodeAndDecode(t, tkhd)
+		degrees, ok := tkhd.Rotation()
+		if degrees != tc.degrees || ok != tc.ok {
+			t.Errorf("matrix %v: got rotation %d, %t instead of %d, %t", tc.matrix, degrees, ok, tc.degrees, tc.ok)
+		}
+	}
+}
@@ -4,6 +4,7 @@ import (
 	"fmt"
 	"io"
 
+	"github.com/Eyevinn/mp4ff/avc"
 	"github.com/Eyevinn/mp4ff/bits"
 )
 
@@ -18,6 +19,7 @@ const DefaultTrakID = 1
 type TrakBox struct {
 	Tkhd     *TkhdBox
 	Edts     *EdtsBox
+	Tref     *TrefBox
 	Mdia     *MdiaBox
 	Children []Box
 }
@@ -36,6 +38,8 @@ func (t *TrakBox) AddChild(child Box) {
 		t.Mdia = box
 	case *EdtsBox:
 		t.Edts = box
+	case *TrefBox:
+		t.Tref = box
 	}
 	t.Children = append(t.Children, child)
 }
@@ -102,26 +106,106 @@ func (t *TrakBox) GetNrSamples() uint32 {
 	return stbl.Stsz.GetNrSamples()
 }
 
+// FrameRate - frame rate of a video track. For AVC, the SPS VUI timing info is used if present.
+// Otherwise, the frame rate is number of samples * mdhd timescale / total duration in stts.
+// ok is false if the frame rate cannot be determined.
+func (t *TrakBox) FrameRate() (fps float64, ok bool) {
+	stbl := t.Mdia.Minf.Stbl
+	if avcX := stbl.Stsd.AvcX; avcX != nil && avcX.AvcC != nil && len(avcX.AvcC.SPSnalus) > 0 {
+		sps, err := avc.ParseSPSNALUnit(avcX.AvcC.SPSnalus[0], true)
+		if err == nil {
+			if fps, ok := sps.FrameRate(); ok {
+				return fps, true
+			}
+		}
+	}
+	var nrSamples uint64
+	for _, count := range stbl.Stts.SampleCount {
+		nrSamples += uint64(count)
+	}
+	totDur := stbl.Stts.TotalDuration()
+	if totDur == 0 {
+		return 0, false
+	}
+	return float64(nrSamples) * float64(t.Mdia.Mdhd.Timescale) / float64(totDur), true
+}
+
+// AvgBitrate - average bitrate in bits per second. The avgBitrate of a btrt box in the sample entry is used if present.
+// Otherwise, the bitrate is total sample size in stsz divided by total duration in stts.
+// ok is false if the bitrate cannot be determined.
+func (t *TrakBox) AvgBitrate() (bitrate uint64, ok bool) {
+	stbl := t.Mdia.Minf.Stbl
+	if btrt := stbl.Stsd.GetBtrt(); btrt != nil && btrt.AvgBitrate != 0 {
+		return uint64(btrt.AvgBitrate), true
+	}
+	nrSamples := stbl.Stsz.GetNrSamples()
+	totDur := stbl.Stts.TotalDuration()
+	if nrSamples == 0 || totDur == 0 {
+		return 0, false
+	}
+	totSize, err := stbl.Stsz.GetTotalSampleSize(1, nrSamples)
+	if err != nil {
+		return 0, false
+	}
+	return totSize * 8 * uint64(t.Mdia.Mdhd.Timescale) / totDur, true
+}
+
+// GetSyncSampleNrAtOrBefore - get the number (one-based) of the nearest sync sample
+// with decode time at or before decodeTime given in the mdhd timescale.
+// A time after the last sample gives the last sync sample. Without stss box, all samples are sync samples.
+func (t *TrakBox) GetSyncSampleNrAtOrBefore(decodeTime uint64) (sampleNr uint32, err error) {
+	stbl := t.Mdia.Minf.Stbl
+	nrSamples := stbl.Stsz.GetNrSamples()
+	if nrSamples == 0 {
+		return 0, fmt.Errorf("no samples in track")
+	}
+	sampleNr = nrSamples
+	if stbl.Stts == nil {
+		return 0, fmt.Errorf("no stts box")
+	}
+	if nr, err := stbl.Stts.GetSampleNrAtTime(decodeTime); err == nil && nr <= nrSamples {
+		sampleNr = nr
+		if decTime, _ := stbl.Stts.GetDecodeTime(nr); decTime > decodeTime {
+			sampleNr-- // The sample found starts after decodeTime
+		}
+	}
+	if stbl.Stss == nil {
+		return sampleNr, nil
+	}
+	syncNr, ok := stbl.Stss.SyncSampleNrAtOrBefore(sampleNr)
+	if !ok {
+		return 0, fmt.Errorf("no sync sample at or before sample %d", sampleNr)
+	}
+	return syncNr, nil
+}
+
 // GetSampleData - get sample metadata for a specific interval of samples defined in moov.
 // If going outside the range of available samples, an error is returned.
+// If the sample tables have inconsistent sample counts, a *SampleCountMismatchError is returned.
 func (t *TrakBox) GetSampleData(startSampleNr, endSampleNr uint32) ([]Sample, error) {
 	stbl := t.Mdia.Minf.Stbl
 	nrSamples := stbl.Stsz.GetNrSamples()
 	if startSampleNr < 1 || endSampleNr > nrSamples {
 		return nil, fmt.Errorf("sample interval %d-%d not inside available %d-%d", startSampleNr, endSampleNr, 1, nrSamples)
 	}
-	samples := make([]Sample, endSampleNr-startSampleNr+1)
 	stts := stbl.Stts
 	ctts := stbl.Ctts
 	stss := stbl.Stss
 	sdtp := stbl.Sdtp
+	if stts == nil {
+		return nil, fmt.Errorf("no stts box")
+	}
+	if err := stbl.CheckSampleCounts(); err != nil {
+		return nil, err
+	}
+	samples := make([]Sample, endSampleNr-startSampleNr+1)
 
 	for nr := startSampleNr; nr <= endSampleNr; nr++ {
 		var cto int32
 		if ctts != nil {
 			cto = ctts.GetCompositionTimeOffset(nr)
 		}
-		samples[nr-1] = Sample{
+		samples[nr-startSampleNr] = Sample{
 			Flags:                 createSampleFlagsFromProgressiveBoxes(stss, sdtp, nr),
 			Dur:                   stts.GetDur(nr),
 			Size:                  stbl.Stsz.GetSampleSize(int(nr)),
@@ -133,14 +217,16 @@ func (t *TrakBox) GetSampleData(startSampleNr, endSampleNr uint32) ([]Sample, er
 
 func createSampleFlagsFromProgressiveBoxes(stss *StssBox, sdtp *SdtpBox, sampleNr uint32) uint32 {
 	var sampleFlags SampleFlags
+	isSync := true // All samples are sync samples if there is no stss box
 	if stss != nil {
-		isSync := stss.IsSyncSample(uint32(sampleNr))
-		sampleFlags.SampleIsNonSync = !isSync
-		if isSync {
-			sampleFlags.SampleDependsOn = 2 //2 = does not depend on others (I-picture). May be overridden by sdtp entry
-		}
+		isSync = stss.IsSyncSample(uint32(sampleNr))
+	}
+	sampleFlags.SampleIsNonSync = !isSync
+	if isSync {
+		sampleFlags.SampleDependsOn = 2 //2 = does not depend on others (I-picture). May be overridden by sdtp entry
 	}
-	if sdtp != nil {
+	// A short sdtp is accepted, since its dependency flags are optional
+	if sdtp != nil && int(sampleNr) <= len(sdtp.Entries) {
 		entry := sdtp.Entries[uint32(sampleNr)-1] // table starts at 0, but sampleNr is one-based
 		sampleFlags.IsLeading = entry.IsLeading()
 		sampleFlags.SampleDependsOn = entry.SampleDependsOn()
@@ -175,10 +261,13 @@ func (t *TrakBox) GetRangesForSampleInterval(startSampleNr, endSampleNr uint32)
 	lastChunkIdx := len(chunks) - 1
 	for idx, chunk := range chunks {
 		var offset uint64
-		if stco != nil {
+		switch {
+		case stco != nil:
 			offset, err = stco.GetOffset(int(chunk.ChunkNr))
-		} else if stbl.Co64 != nil {
+		case co64 != nil:
 			offset, err = co64.GetOffset(int(chunk.ChunkNr))
+		default:
+			err = fmt.Errorf("neither stco nor co64 available")
 		}
 		if err != nil {
 			return nil, err
@@ -204,3 +293,48 @@ func (t *TrakBox) GetRangesForSampleInterval(startSampleNr, endSampleNr uint32)
 	}
 	return dataRanges, nil
 }
+
+// GetSampleRanges - get one range inside file per sample for sample range [startSampleNr, endSampleNr].
+// The ranges make it possible to read or serve individual samples without copying mdat data.
+// If the sample tables have inconsistent sample counts, a *SampleCountMismatchError is returned.
+func (t *TrakBox) GetSampleRanges(startSampleNr, endSampleNr uint32) ([]DataRange, error) {
+	stbl := t.Mdia.Minf.Stbl
+	nrSamples := stbl.Stsz.GetNrSamples()
+	if startSampleNr < 1 || endSampleNr > nrSamples {
+		return nil, fmt.Errorf("sample interval %d-%d not inside available %d-%d", startSampleNr, endSampleNr, 1, nrSamples)
+	}
+	if err := stbl.CheckSampleCounts(); err != nil {
+		return nil, err
+	}
+	chunks, err := stbl.Stsc.GetContainingChunks(startSampleNr, endSampleNr)
+	if err != nil {
+		return nil, err
+	}
+	dataRanges := make([]DataRange, 0, endSampleNr-startSampleNr+1)
+	for _, chunk := range chunks {
+		var offset uint64
+		switch {
+		case stbl.Stco != nil:
+			offset, err = stbl.Stco.GetOffset(int(chunk.ChunkNr))
+		case stbl.Co64 != nil:
+			offset, err = stbl.Co64.GetOffset(int(chunk.ChunkNr))
+		default:
+			err = fmt.Errorf("neither stco nor co64 available")
+		}
+		if err != nil {
+			return nil, err
+		}
+		endNrInChunk := chunk.StartSampleNr + chunk.NrSamples - 1
+		if endNrInChunk > endSampleNr {
+			endNrInChunk = endSampleNr
+		}
+		for nr := chunk.StartSampleNr; nr <= endNrInChunk; nr++ {
+			size := uint64(stbl.Stsz.GetSampleSize(int(nr)))
+			if nr >= startSampleNr {
+				dataRanges = append(dataRanges, DataRange{Offset: offset, Size: size})
+			}
+			offset += size
+		}
+	}
+	return dataRanges, nil
+}
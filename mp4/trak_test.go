@@ -1,6 +1,7 @@
 package mp4_test
 
 import (
+	"bytes"
 	"os"
 	"testing"
 
@@ -42,3 +43,284 @@ func TestTrakSampleFunctions(t *testing.T) {
 		t.Fatalf("expected 1 range, got %d", len(ranges))
 	}
 }
+
+func TestTrakSampleSyncFlags(t *testing.T) {
+	mf, err := mp4.ReadMP4File("testdata/bbb_prog_10s.mp4")
+	if err != nil {
+		t.Fatal(err)
+	}
+	videoTrak, audioTrak := mf.Moov.Traks[0], mf.Moov.Traks[1]
+	var startNr, endNr uint32 = 15, 17
+	samples, err := videoTrak.GetSampleData(startNr, endNr)
+	if err != nil {
+		t.Fatal(err)
+	}
+	for i, s := range samples {
+		sampleNr := startNr + uint32(i)
+		wantedSync := sampleNr == 16 // stss has 1, 16, 64, 112, 160, 208
+		if s.IsSync() != wantedSync {
+			t.Errorf("video sample %d: got sync %t instead of %t", sampleNr, s.IsSync(), wantedSync)
+		}
+	}
+	if audioTrak.Mdia.Minf.Stbl.Stss != nil {
+		t.Fatal("expected no stss box in audio track")
+	}
+	samples, err = audioTrak.GetSampleData(2, 4)
+	if err != nil {
+		t.Fatal(err)
+	}
+	for i, s := range samples {
+		if !s.IsSync() {
+			t.Errorf("audio sample %d without stss is not sync", 2+i)
+		}
+	}
+}
+
+func TestTrakShortSdtp(t *testing.T) {
+	f, err := mp4.ReadMP4File("testdata/prog_8s.mp4")
+	if err != nil {
+		t.Fatal(err)
+	}
+	trak := f.Moov.Traks[1] // video track with sdtp
+	nrSamples := trak.GetNrSamples()
+	full, err := trak.GetSampleData(1, nrSamples)
+	if err != nil {
+		t.Fatal(err)
+	}
+	stbl := trak.Mdia.Minf.Stbl
+	nrSdtpEntries := len(stbl.Sdtp.Entries) / 2
+	stbl.Sdtp.Entries = stbl.Sdtp.Entries[:nrSdtpEntries]
+	short, err := trak.GetSampleData(1, nrSamples)
+	if err != nil {
+		t.Fatal(err)
+	}
+	for i := range short {
+		if i < nrSdtpEntries {
+			if short[i].Flags != full[i].Flags {
+				t.Errorf("sample %d: got flags %08x instead of %08x", i+1, short[i].Flags, full[i].Flags)
+			}
+			continue
+		}
+		// Without sdtp entry, the flags only depend on stss
+		wantedFlags := mp4.NonSyncSampleFlags
+		if stbl.Stss.IsSyncSample(uint32(i + 1)) {
+			wantedFlags = mp4.SyncSampleFlags
+		}
+		if short[i].Flags != wantedFlags {
+			t.Errorf("sample %d without sdtp entry: got flags %08x instead of %08x", i+1, short[i].Flags, wantedFlags)
+		}
+	}
+}
+
+func TestTrakGetSyncSampleNrAtOrBefore(t *testing.T) {
+	mf, err := mp4.ReadMP4File("testdata/bbb_prog_10s.mp4")
+	if err != nil {
+		t.Fatal(err)
+	}
+	videoTrak, audioTrak := mf.Moov.Traks[0], mf.Moov.Traks[1]
+	testCases := []struct {
+		desc         string
+		trak         *mp4.TrakBox
+		decodeTime   uint64
+		wantedSyncNr uint32
+	}{
+		// Video has timescale 12288, sample duration 512, and stss 1, 16, 64, 112, 160, 208
+		{desc: "video start", trak: videoTrak, decodeTime: 0, wantedSyncNr: 1},
+		{desc: "video before sync sample 16", trak: videoTrak, decodeTime: 15*512 - 1, wantedSyncNr: 1},
+		{desc: "video at sync sample 16", trak: videoTrak, decodeTime: 15 * 512, wantedSyncNr: 16},
+		{desc: "video in sample 63", trak: videoTrak, decodeTime: 62*512 + 100, wantedSyncNr: 16},
+		{desc: "video after end", trak: videoTrak, decodeTime: 10 * 12288, wantedSyncNr: 208},
+		// Audio has no stss and sample duration 1024
+		{desc: "audio at sample 6", trak: audioTrak, decodeTime: 5 * 1024, wantedSyncNr: 6},
+		{desc: "audio in sample 6", trak: audioTrak, decodeTime: 5*1024 + 1, wantedSyncNr: 6},
+	}
+	for _, tc := range testCases {
+		t.Run(tc.desc, func(t *testing.T) {
+			gotNr, err := tc.trak.GetSyncSampleNrAtOrBefore(tc.decodeTime)
+			if err != nil {
+				t.Fatal(err)
+			}
+			if gotNr != tc.wantedSyncNr {
+				t.Errorf("got sync sample %d instead of %d", gotNr, tc.wantedSyncNr)
+			}
+		})
+	}
+}
+
+func TestTrakGetSampleRanges(t *testing.T) {
+	data, err := os.ReadFile("testdata/bbb_prog_10s.mp4")
+	if err != nil {
+		t.Fatal(err)
+	}
+	mf, err := mp4.DecodeFile(bytes.NewReader(data))
+	if err != nil {
+		t.Fatal(err)
+	}
+	for _, trak := range mf.Moov.Traks {
+		var startNr, endNr uint32 = 3, 40
+		sampleRanges, err := trak.GetSampleRanges(startNr, endNr)
+		if err != nil {
+			t.Fatal(err)
+		}
+		if len(sampleRanges) != int(endNr-startNr+1) {
+			t.Fatalf("track %d: got %d ranges instead of %d", trak.Tkhd.TrackID, len(sampleRanges), endNr-startNr+1)
+		}
+		var sampleData []byte
+		for i, r := range sampleRanges {
+			wantedSize := trak.Mdia.Minf.Stbl.Stsz.GetSampleSize(int(startNr) + i)
+			if r.Size != uint64(wantedSize) {
+				t.Errorf("track %d, sample %d: got size %d instead of %d", trak.Tkhd.TrackID, int(startNr)+i, r.Size, wantedSize)
+			}
+			sampleData = append(sampleData, data[r.Offset:r.Offset+r.Size]...)
+		}
+		chunkRanges, err := trak.GetRangesForSampleInterval(startNr, endNr)
+		if err != nil {
+			t.Fatal(err)
+		}
+		var chunkData []byte
+		for _, r := range chunkRanges {
+			chunkData = append(chunkData, data[r.Offset:r.Offset+r.Size]...)
+		}
+		if !bytes.Equal(sampleData, chunkData) {
+			t.Errorf("track %d: sample data differs from data in chunk ranges", trak.Tkhd.TrackID)
+		}
+	}
+	_, err = mf.Moov.Traks[0].GetSampleRanges(0, 2)
+	if err == nil {
+		t.Error("expected error for sample number 0")
+	}
+}
+
+func TestTrakFrameRate(t *testing.T) {
+	f, err := mp4.ReadMP4File("testdata/prog_8s.mp4")
+	if err != nil {
+		t.Fatal(err)
+	}
+	videoTrak := f.Moov.Traks[1]
+	fps, ok := videoTrak.FrameRate()
+	if !ok || fps != 30 {
+		t.Errorf("got frame rate %f, %t instead of 30, true", fps, ok)
+	}
+
+	trak := mp4.CreateEmptyTrak(1, 90000, "video", "und")
+	if _, ok := trak.FrameRate(); ok {
+		t.Error("got frame rate for track without samples")
+	}
+	stts := trak.Mdia.Minf.Stbl.Stts
+	stts.SampleCount = []uint32{24, 1}
+	stts.SampleTimeDelta = []uint32{3600, 3600}
+	fps, ok = trak.FrameRate()
+	if !ok || fps != 25 {
+		t.Errorf("got frame rate %f, %t instead of 25, true", fps, ok)
+	}
+}
+
+func TestTrakAvgBitrate(t *testing.T) {
+	f, err := mp4.ReadMP4File("testdata/prog_8s.mp4")
+	if err != nil {
+		t.Fatal(err)
+	}
+	testCases := []struct {
+		desc    string
+		trak    *mp4.TrakBox
+		bitrate uint64
+	}{
+		{"audio from sample sizes", f.Moov.Traks[0], 48752},
+		{"video from btrt", f.Moov.Traks[1], 134384},
+	}
+	for _, tc := range testCases {
+		bitrate, ok := tc.trak.AvgBitrate()
+		if !ok || bitrate != tc.bitrate {
+			t.Errorf("%s: got bitrate %d, %t instead of %d, true", tc.desc, bitrate, ok, tc.bitrate)
+		}
+	}
+	trak := mp4.CreateEmptyTrak(1, 90000, "video", "und")
+	if _, ok := trak.AvgBitrate(); ok {
+		t.Error("got bitrate for track without samples")
+	}
+}
+
+func TestTrakMismatchedStscStco(t *testing.T) {
+	trak := mp4.CreateEmptyTrak(1, 90000, "video", "und")
+	stbl := trak.Mdia.Minf.Stbl
+	stbl.Stsz.SampleUniformSize = 100
+	stbl.Stsz.SampleNumber = 10
+	// Two samples in chunks 1-2, and then one sample per chunk, so 10 samples need 8 chunks
+	_ = stbl.Stsc.AddEntry(1, 2, 1)
+	_ = stbl.Stsc.AddEntry(3, 1, 1)
+	stbl.Stco.ChunkOffset = []uint32{1000, 1200, 1300}
+	stbl.Stts.SampleCount, stbl.Stts.SampleTimeDelta = []uint32{10}, []uint32{3000}
+	ranges, err := trak.GetSampleRanges(1, 5)
+	if err != nil {
+		t.Fatal(err)
+	}
+	if len(ranges) != 5 || ranges[4].Offset != 1300 {
+		t.Errorf("got ranges %v", ranges)
+	}
+	if _, err := trak.GetSampleRanges(1, 10); err == nil {
+		t.Error("expected error for samples in chunks beyond stco")
+	}
+	if _, err := trak.GetRangesForSampleInterval(6, 10); err == nil {
+		t.Error("expected error for samples in chunks beyond stco")
+	}
+	stbl.Stco = nil
+	if _, err := trak.GetRangesForSampleInterval(1, 2); err == nil {
+		t.Error("expected error without stco and co64")
+	}
+}
+
+func TestTrakTruncatedSampleTables(t *testing.T) {
+	data, err := os.ReadFile("testdata/prog_8s.mp4")
+	if err != nil {
+		t.Fatal(err)
+	}
+	testCases := []struct {
+		desc              string
+		truncate          func(stbl *mp4.StblBox)
+		wantSampleDataErr bool
+		wantRangesErr     bool
+		wantCountErr      bool // sample count mismatch in GetSampleData and GetSampleRanges
+	}{
+		{desc: "stts", truncate: func(stbl *mp4.StblBox) {
+			stbl.Stts.SampleCount, stbl.Stts.SampleTimeDelta = nil, nil
+		}, wantCountErr: true},
+		{desc: "no stts", truncate: func(stbl *mp4.StblBox) { stbl.Stts = nil }, wantSampleDataErr: true},
+		{desc: "ctts", truncate: func(stbl *mp4.StblBox) {
+			n := len(stbl.Ctts.SampleOffset) / 2
+			stbl.Ctts.SampleOffset, stbl.Ctts.EndSampleNr = stbl.Ctts.SampleOffset[:n], stbl.Ctts.EndSampleNr[:n+1]
+		}, wantCountErr: true},
+		{desc: "sdtp", truncate: func(stbl *mp4.StblBox) {
+			stbl.Sdtp.Entries = stbl.Sdtp.Entries[:len(stbl.Sdtp.Entries)/2]
+		}},
+		{desc: "stss", truncate: func(stbl *mp4.StblBox) { stbl.Stss.SampleNumber = stbl.Stss.SampleNumber[:1] }},
+		{desc: "stsc", truncate: func(stbl *mp4.StblBox) { stbl.Stsc.Entries = stbl.Stsc.Entries[:1] }},
+		{desc: "empty stsc", truncate: func(stbl *mp4.StblBox) { stbl.Stsc.Entries = nil }, wantRangesErr: true},
+		{desc: "stco", truncate: func(stbl *mp4.StblBox) {
+			stbl.Stco.ChunkOffset = stbl.Stco.ChunkOffset[:len(stbl.Stco.ChunkOffset)/2]
+		}, wantRangesErr: true},
+	}
+	for _, tc := range testCases {
+		t.Run(tc.desc, func(t *testing.T) {
+			f, err := mp4.DecodeFile(bytes.NewReader(data))
+			if err != nil {
+				t.Fatal(err)
+			}
+			trak := f.Moov.Traks[1] // video track with all sample table boxes
+			tc.truncate(trak.Mdia.Minf.Stbl)
+			nrSamples := trak.GetNrSamples()
+			_, err = trak.GetSampleData(1, nrSamples)
+			if (err != nil) != (tc.wantSampleDataErr || tc.wantCountErr) {
+				t.Errorf("GetSampleData: got error %v", err)
+			}
+			_, err = trak.GetSampleRanges(1, nrSamples)
+			if (err != nil) != (tc.wantRangesErr || tc.wantCountErr) {
+				t.Errorf("GetSampleRanges: got error %v", err)
+			}
+			_, err = trak.GetRangesForSampleInterval(1, nrSamples)
+			if (err != nil) != tc.wantRangesErr {
+				t.Errorf("GetRangesForSampleInterval: got error %v", err)
+			}
+			_, _ = trak.GetSyncSampleNrAtOrBefore(360000)
+		})
+	}
+}
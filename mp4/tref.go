@@ -17,6 +17,16 @@ func (b *TrefBox) AddChild(box Box) {
 	b.Children = append(b.Children, box)
 }
 
+// GetTrackIDs - track IDs referenced with reference type refType (e.g. chap). Returns nil if not present.
+func (b *TrefBox) GetTrackIDs(refType string) []uint32 {
+	for _, c := range b.Children {
+		if ttb, ok := c.(*TrefTypeBox); ok && ttb.Name == refType {
+			return ttb.TrackIDs
+		}
+	}
+	return nil
+}
+
 // DecodeTref - box-specific decode
 func DecodeTref(hdr BoxHeader, startPos uint64, r io.Reader) (Box, error) {
 	children, err := DecodeContainerChildren(hdr, startPos+8, startPos+hdr.Size, r)
@@ -75,7 +85,7 @@ func (b *TrefBox) Info(w io.Writer, specificBoxLevels, indent, indentStep string
 
 // TrefTypeBox - TrackReferenceTypeBox - ISO/IEC 14496-12 Ed. 9 Sec. 8.3
 // Name can be one of hint, cdsc, font, hind, vdep, vplx, subt (ISO/IEC 14496-12)
-// dpnd, ipir, mpod, sync (ISO/IEC 14496-14)
+// dpnd, ipir, mpod, sync (ISO/IEC 14496-14), chap (QuickTime chapter track)
 type TrefTypeBox struct {
 	Name     string
 	TrackIDs []uint32
@@ -1,6 +1,7 @@
 package mp4_test
 
 import (
+	"bytes"
 	"testing"
 
 	"github.com/Eyevinn/mp4ff/mp4"
@@ -20,5 +21,35 @@ func TestTref(t *testing.T) {
 	tref.AddChild(&mp4.TrefTypeBox{Name: "ipir", TrackIDs: []uint32{10}})
 	tref.AddChild(&mp4.TrefTypeBox{Name: "mpod", TrackIDs: []uint32{11}})
 	tref.AddChild(&mp4.TrefTypeBox{Name: "sync", TrackIDs: []uint32{12, 13}})
+	tref.AddChild(&mp4.TrefTypeBox{Name: "chap", TrackIDs: []uint32{14}})
 	boxDiffAfterEncodeAndDecode(t, &tref)
 }
+
+func TestTrakWithChapterReference(t *testing.T) {
+	trak := mp4.NewTrakBox()
+	trak.AddChild(&mp4.TkhdBox{TrackID: 1})
+	tref := &mp4.TrefBox{}
+	tref.AddChild(&mp4.TrefTypeBox{Name: "chap", TrackIDs: []uint32{2}})
+	trak.AddChild(tref)
+
+	buf := bytes.Buffer{}
+	err := trak.Encode(&buf)
+	if err != nil {
+		t.Fatal(err)
+	}
+	box, err := mp4.DecodeBox(0, &buf)
+	if err != nil {
+		t.Fatal(err)
+	}
+	decTrak := box.(*mp4.TrakBox)
+	if decTrak.Tref == nil {
+		t.Fatal("no tref box in decoded trak")
+	}
+	trackIDs := decTrak.Tref.GetTrackIDs("chap")
+	if len(trackIDs) != 1 || trackIDs[0] != 2 {
+		t.Errorf("got chap track IDs %v instead of [2]", trackIDs)
+	}
+	if got := decTrak.Tref.GetTrackIDs("hint"); got != nil {
+		t.Errorf("got hint track IDs %v instead of nil", got)
+	}
+}
@@ -0,0 +1,267 @@
+package mp4
+
+import (
+	"fmt"
+	"io"
+
+	"github.com/Eyevinn/mp4ff/bits"
+)
+
+// Tx3gSample - 3GPP timed text sample (3GPP TS 26.245 Sec. 5.17).
+// The text is followed by optional modifier boxes, of which styl, hlit, and hclr are decoded.
+type Tx3gSample struct {
+	Text           string
+	Styles         []Tx3gStyleRecord
+	Highlight      *Tx3gHighlight
+	HighlightColor *Tx3gRGBA
+}
+
+// Tx3gStyleRecord - style of the characters StartChar to EndChar (exclusive) in a styl box
+type Tx3gStyleRecord struct {
+	StartChar      uint16
+	EndChar        uint16
+	FontID         uint16
+	FaceStyleFlags byte // 1 = bold, 2 = italic, 4 = underline
+	FontSize       byte
+	TextColor      Tx3gRGBA
+}
+
+// Tx3gHighlight - highlighted characters StartChar to EndChar (exclusive) from a hlit box
+type Tx3gHighlight struct {
+	StartChar uint16
+	EndChar   uint16
+}
+
+// Tx3gRGBA - color with red, green, blue, and alpha values
+type Tx3gRGBA [4]byte
+
+// Face style flags of Tx3gStyleRecord
+const (
+	Tx3gBold      byte = 1
+	Tx3gItalic    byte = 2
+	Tx3gUnderline byte = 4
+)
+
+// DecodeTx3gSample - decode text with 16-bit length prefix and the modifier boxes of a tx3g sample.
+// Unknown modifier boxes are skipped.
+func DecodeTx3gSample(data []byte) (*Tx3gSample, error) {
+	sr := bits.NewFixedSliceReader(data)
+	textLen := int(sr.ReadUint16())
+	s := &Tx3gSample{
+		Text: sr.ReadFixedLengthString(textLen),
+	}
+	if err := sr.AccError(); err != nil {
+		return nil, fmt.Errorf("tx3g text: %w", err)
+	}
+	for sr.NrRemainingBytes() > 0 {
+		boxStart := sr.GetPos()
+		size := int(sr.ReadUint32())
+		boxType := sr.ReadFixedLengthString(4)
+		if err := sr.AccError(); err != nil {
+			return nil, fmt.Errorf("tx3g modifier box header: %w", err)
+		}
+		if size < boxHeaderSize || boxStart+size > len(data) {
+			return nil, fmt.Errorf("tx3g modifier box %s: bad size %d", boxType, size)
+		}
+		switch boxType {
+		case "styl":
+			entryCount := int(sr.ReadUint16())
+			for i := 0; i < entryCount; i++ {
+				sty := Tx3gStyleRecord{
+					StartChar:      sr.ReadUint16(),
+					EndChar:        sr.ReadUint16(),
+					FontID:         sr.ReadUint16(),
+					FaceStyleFlags: sr.ReadUint8(),
+					FontSize:       sr.ReadUint8(),
+				}
+				copy(sty.TextColor[:], sr.ReadBytes(4))
+				s.Styles = append(s.Styles, sty)
+			}
+		case "hlit":
+			s.Highlight = &Tx3gHighlight{
+				StartChar: sr.ReadUint16(),
+				EndChar:   sr.ReadUint16(),
+			}
+		case "hclr":
+			var color Tx3gRGBA
+			copy(color[:], sr.ReadBytes(4))
+			s.HighlightColor = &color
+		}
+		if err := sr.AccError(); err != nil {
+			return nil, fmt.Errorf("tx3g modifier box %s: %w", boxType, err)
+		}
+		if sr.GetPos() > boxStart+size {
+			return nil, fmt.Errorf("tx3g modifier box %s: content exceeds size %d", boxType, size)
+		}
+		sr.SetPos(boxStart + size)
+	}
+	return s, nil
+}
+
+// Tx3gBox - 3GPP TextSampleEntry (tx3g) as defined in 3GPP TS 26.245 Sec. 5.16.
+// Child boxes, like the font table ftab, follow the default style.
+type Tx3gBox struct {
+	DataReferenceIndex      uint16
+	DisplayFlags            uint32
+	HorizontalJustification int8
+	VerticalJustification   int8
+	BackgroundColor         Tx3gRGBA
+	DefaultTextBox          Tx3gTextBox
+	DefaultStyle            Tx3gStyleRecord
+	Children                []Box
+}
+
+// Tx3gTextBox - position of text box in pixels relative to the track
+type Tx3gTextBox struct {
+	Top    int16
+	Left   int16
+	Bottom int16
+	Right  int16
+}
+
+const nrTx3gBytesBeforeChildren = 38
+
+// NewTx3gBox - Create new empty tx3g box
+func NewTx3gBox() *Tx3gBox {
+	return &Tx3gBox{DataReferenceIndex: 1}
+}
+
+// AddChild - add a child box
+func (b *Tx3gBox) AddChild(child Box) {
+	b.Children = append(b.Children, child)
+}
+
+// DecodeTx3g - Decode TextSampleEntry (tx3g)
+func DecodeTx3g(hdr BoxHeader, startPos uint64, r io.Reader) (Box, error) {
+	data, err := readBoxBody(r, hdr)
+	if err != nil {
+		return nil, err
+	}
+	sr := bits.NewFixedSliceReader(data)
+	return DecodeTx3gSR(hdr, startPos, sr)
+}
+
+// DecodeTx3gSR - Decode TextSampleEntry (tx3g)
+func DecodeTx3gSR(hdr BoxHeader, startPos uint64, sr bits.SliceReader) (Box, error) {
+	payloadLen := hdr.payloadLen()
+	if payloadLen < nrTx3gBytesBeforeChildren {
+		return nil, fmt.Errorf("tx3g: payload size %d too small", payloadLen)
+	}
+	b := Tx3gBox{}
+	initPos := sr.GetPos()
+	// 14496-12 8.5.2.2 Sample entry (8 bytes)
+	sr.SkipBytes(6) // Skip 6 reserved bytes
+	b.DataReferenceIndex = sr.ReadUint16()
+	b.DisplayFlags = sr.ReadUint32()
+	b.HorizontalJustification = int8(sr.ReadUint8())
+	b.VerticalJustification = int8(sr.ReadUint8())
+	copy(b.BackgroundColor[:], sr.ReadBytes(4))
+	b.DefaultTextBox = Tx3gTextBox{
+		Top:    sr.ReadInt16(),
+		Left:   sr.ReadInt16(),
+		Bottom: sr.ReadInt16(),
+		Right:  sr.ReadInt16(),
+	}
+	b.DefaultStyle = Tx3gStyleRecord{
+		StartChar:      sr.ReadUint16(),
+		EndChar:        sr.ReadUint16(),
+		FontID:         sr.ReadUint16(),
+		FaceStyleFlags: sr.ReadUint8(),
+		FontSize:       sr.ReadUint8(),
+	}
+	copy(b.DefaultStyle.TextColor[:], sr.ReadBytes(4))
+	if err := sr.AccError(); err != nil {
+		return nil, err
+	}
+	pos := startPos + uint64(hdr.Hdrlen+nrTx3gBytesBeforeChildren)
+	for sr.GetPos()-initPos < payloadLen {
+		box, err := DecodeBoxSR(pos, sr)
+		if err != nil {
+			return nil, err
+		}
+		b.AddChild(box)
+		pos += box.Size()
+	}
+	return &b, sr.AccError()
+}
+
+// Type - return box type
+func (b *Tx3gBox) Type() string {
+	return "tx3g"
+}
+
+// Size - return calculated size
+func (b *Tx3gBox) Size() uint64 {
+	size := uint64(boxHeaderSize + nrTx3gBytesBeforeChildren)
+	for _, child := range b.Children {
+		size += child.Size()
+	}
+	return size
+}
+
+// Encode - write box to w via a SliceWriter
+func (b *Tx3gBox) Encode(w io.Writer) error {
+	sw := bits.NewFixedSliceWriter(int(b.Size()))
+	err := b.EncodeSW(sw)
+	if err != nil {
+		return err
+	}
+	_, err = w.Write(sw.Bytes())
+	return err
+}
+
+// EncodeSW - write box to sw
+func (b *Tx3gBox) EncodeSW(sw bits.SliceWriter) error {
+	err := EncodeHeaderSW(b, sw)
+	if err != nil {
+		return err
+	}
+	sw.WriteZeroBytes(6)
+	sw.WriteUint16(b.DataReferenceIndex)
+	sw.WriteUint32(b.DisplayFlags)
+	sw.WriteUint8(byte(b.HorizontalJustification))
+	sw.WriteUint8(byte(b.VerticalJustification))
+	sw.WriteBytes(b.BackgroundColor[:])
+	sw.WriteInt16(b.DefaultTextBox.Top)
+	sw.WriteInt16(b.DefaultTextBox.Left)
+	sw.WriteInt16(b.DefaultTextBox.Bottom)
+	sw.WriteInt16(b.DefaultTextBox.Right)
+	sty := b.DefaultStyle
+	sw.WriteUint16(sty.StartChar)
+	sw.WriteUint16(sty.EndChar)
+	sw.WriteUint16(sty.FontID)
+	sw.WriteUint8(sty.FaceStyleFlags)
+	sw.WriteUint8(sty.FontSize)
+	sw.WriteBytes(sty.TextColor[:])
+	for _, child := range b.Children {
+		err = child.EncodeSW(sw)
+		if err != nil {
+			return err
+		}
+	}
+	return sw.AccError()
+}
+
+// Info - write box-specific information
+func (b *Tx3gBox) Info(w io.Writer, specificBoxLevels, indent, indentStep string) error {
+	bd := newInfoDumper(w, indent, b, -1, 0)
+	bd.write(" - dataReferenceIndex: %d", b.DataReferenceIndex)
+	bd.write(" - displayFlags: %08x", b.DisplayFlags)
+	bd.write(" - justification: horizontal=%d vertical=%d", b.HorizontalJustification, b.VerticalJustification)
+	bd.write(" - backgroundColor: %x", b.BackgroundColor[:])
+	tb := b.DefaultTextBox
+	bd.write(" - defaultTextBox: top=%d left=%d bottom=%d right=%d", tb.Top, tb.Left, tb.Bottom, tb.Right)
+	sty := b.DefaultStyle
+	bd.write(" - defaultStyle: fontID=%d faceStyleFlags=%d fontSize=%d textColor=%x",
+		sty.FontID, sty.FaceStyleFlags, sty.FontSize, sty.TextColor[:])
+	if bd.err != nil {
+		return bd.err
+	}
+	for _, child := range b.Children {
+		err := child.Info(w, specificBoxLevels, indent+indentStep, indent)
+		if err != nil {
+			return err
+		}
+	}
+	return nil
+}
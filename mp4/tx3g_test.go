@@ -0,0 +1,116 @@
+package mp4_test
+
+import (
+	"bytes"
+	"encoding/hex"
+	"testing"
+
+	"github.com/Eyevinn/mp4ff/mp4"
+	"github.com/go-test/deep"
+)
+
+func TestDecodeTx3gSample(t *testing.T) {
+	testCases := []struct {
+		desc    string
+		hexData string
+		wanted  *mp4.Tx3gSample
+	}{
+		{
+			desc:    "empty",
+			hexData: "0000",
+			wanted:  &mp4.Tx3gSample{Text: ""},
+		},
+		{
+			desc:    "no styling",
+			hexData: "0003616263",
+			wanted:  &mp4.Tx3gSample{Text: "abc"},
+		},
+		{
+			desc: "styl, hlit, hclr, and blnk",
+			hexData: "0006486920796f75" +
+				"000000167374796c" + "0001" + "0000000200010312ffff00ff" +
+				"0000000c686c6974" + "00030006" +
+				"0000000c68636c72" + "0000ffff" +
+				"0000000c626c6e6b" + "00000002",
+			wanted: &mp4.Tx3gSample{
+				Text: "Hi you",
+				Styles: []mp4.Tx3gStyleRecord{
+					{StartChar: 0, EndChar: 2, FontID: 1, FaceStyleFlags: mp4.Tx3gBold | mp4.Tx3gItalic, FontSize: 18,
+						TextColor: mp4.Tx3gRGBA{0xff, 0xff, 0x00, 0xff}},
+				},
+				Highlight:      &mp4.Tx3gHighlight{StartChar: 3, EndChar: 6},
+				HighlightColor: &mp4.Tx3gRGBA{0x00, 0x00, 0xff, 0xff},
+			},
+		},
+	}
+	for _, tc := range testCases {
+		data, err := hex.DecodeString(tc.hexData)
+		if err != nil {
+			t.Fatal(err)
+		}
+		got, err := mp4.DecodeTx3gSample(data)
+		if err != nil {
+			t.Errorf("%s: %s", tc.desc, err)
+			continue
+		}
+		if diff := deep.Equal(got, tc.wanted); diff != nil {
+			t.Errorf("%s: %v", tc.desc, diff)
+		}
+	}
+}
+
+func TestDecodeBadTx3gSample(t *testing.T) {
+	testCases := []struct {
+		desc    string
+		hexData string
+	}{
+		{"too short for text length", "00"},
+		{"text shorter than length", "000561"},
+		{"truncated box header", "000161000000"},
+		{"box size smaller than header", "000161" + "000000047374796c"},
+		{"box size beyond sample", "000161" + "000000167374796c" + "0001"},
+		{"styl content exceeds box size", "000161" + "0000000c7374796c" + "0001" + "0000000200010312ffff00ff"},
+	}
+	for _, tc := range testCases {
+		data, err := hex.DecodeString(tc.hexData)
+		if err != nil {
+			t.Fatal(err)
+		}
+		if _, err := mp4.DecodeTx3gSample(data); err == nil {
+			t.Errorf("%s: expected error", tc.desc)
+		}
+	}
+}
+
+func TestTx3gSampleEntry(t *testing.T) {
+	rawHex := "0000004074783367" + "0000000000000001" + "00000000" + "01ff" + "000000ff" +
+		"0000000000480140" + "000000000001001200ffffff" +
+		"0000001266746162" + "0001" + "0001" + "05" + "5365726966"
+	data, err := hex.DecodeString(rawHex)
+	if err != nil {
+		t.Fatal(err)
+	}
+	cmpAfterDecodeEncodeBox(t, data)
+	box, err := mp4.DecodeBox(0, bytes.NewReader(data))
+	if err != nil {
+		t.Fatal(err)
+	}
+	tx3g := box.(*mp4.Tx3gBox)
+	if tx3g.HorizontalJustification != 1 || tx3g.VerticalJustification != -1 ||
+		tx3g.DefaultTextBox.Right != 320 || tx3g.DefaultStyle.FontSize != 18 || len(tx3g.Children) != 1 {
+		t.Errorf("unexpected tx3g box %+v", *tx3g)
+	}
+	stsd := mp4.NewStsdBox()
+	stsd.AddChild(tx3g)
+	if stsd.Tx3g != tx3g {
+		t.Error("stsd.Tx3g not set")
+	}
+
+	newTx3g := mp4.NewTx3gBox()
+	newTx3g.DefaultStyle.FontSize = 24
+	boxDiffAfterEncodeAndDecode(t, newTx3g)
+
+	if _, err := mp4.DecodeBox(0, bytes.NewReader(data[:40])); err == nil {
+		t.Error("expected error for truncated tx3g box")
+	}
+}
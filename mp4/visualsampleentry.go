@@ -8,7 +8,7 @@ import (
 	"github.com/Eyevinn/mp4ff/hevc"
 )
 
-// VisualSampleEntryBox Video Sample Description box (avc1/avc3/hvc1/hev1...)
+// VisualSampleEntryBox Video Sample Description box (avc1/avc3/hvc1/hev1/dvh1/dvhe...)
 type VisualSampleEntryBox struct {
 	name               string
 	DataReferenceIndex uint16
@@ -87,7 +87,7 @@ func (b *VisualSampleEntryBox) AddChild(child Box) {
 	b.Children = append(b.Children, child)
 }
 
-// DecodeVisualSampleEntry decodes avc1/avc3/hvc1/hev1/vvc1/vvi1 box
+// DecodeVisualSampleEntry decodes avc1/avc3/hvc1/hev1/dvh1/dvhe/vvc1/vvi1 box
 func DecodeVisualSampleEntry(hdr BoxHeader, startPos uint64, r io.Reader) (Box, error) {
 	data, err := readBoxBody(r, hdr)
 	if err != nil {
@@ -97,7 +97,7 @@ func DecodeVisualSampleEntry(hdr BoxHeader, startPos uint64, r io.Reader) (Box,
 	return DecodeVisualSampleEntrySR(hdr, startPos, sr)
 }
 
-// DecodeVisualSampleEntrySR decodes avc1/avc3/hvc1/hev1/vvc1/vvi1 box
+// DecodeVisualSampleEntrySR decodes avc1/avc3/hvc1/hev1/dvh1/dvhe/vvc1/vvi1 box
 func DecodeVisualSampleEntrySR(hdr BoxHeader, startPos uint64, sr bits.SliceReader) (Box, error) {
 	b := VisualSampleEntryBox{name: hdr.Name}
 
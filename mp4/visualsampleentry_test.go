@@ -58,3 +58,36 @@ func TestVisualSampleEntryBoxVP9(t *testing.T) {
 		t.Errorf("")
 	}
 }
+
+func TestVisualSampleEntryBoxDolbyVision(t *testing.T) {
+	vps, _ := hex.DecodeString(hevcVPSnalu)
+	sps, _ := hex.DecodeString(hevcSPSnalu)
+	pps, _ := hex.DecodeString(hevcPPSnalu)
+	hvcC, err := mp4.CreateHvcC([][]byte{vps}, [][]byte{sps}, [][]byte{pps}, true, true, true, true)
+	if err != nil {
+		t.Fatal(err)
+	}
+	for _, name := range []string{"dvh1", "dvhe"} {
+		dvhx := mp4.CreateVisualSampleEntryBox(name, 960, 540, hvcC)
+		boxDiffAfterEncodeAndDecode(t, dvhx)
+		stsd := mp4.NewStsdBox()
+		stsd.AddChild(dvhx)
+		if stsd.HvcX != dvhx {
+			t.Errorf("%s not set as HvcX in stsd", name)
+		}
+		if family, supported := mp4.GetCodecFamily(dvhx); family != mp4.CodecFamilyDolbyVision || !supported {
+			t.Errorf("%s: got codec family %s and supported %t", name, family, supported)
+		}
+	}
+	hvc1 := mp4.CreateVisualSampleEntryBox("hvc1", 960, 540, hvcC)
+	if family, supported := mp4.GetCodecFamily(hvc1); family != mp4.CodecFamilyHevc || !supported {
+		t.Errorf("hvc1: got codec family %s and supported %t", family, supported)
+	}
+	hvc1.AddChild(mp4.CreateUnknownBox("dvcC", 32, make([]byte, 24)))
+	if family, _ := mp4.GetCodecFamily(hvc1); family != mp4.CodecFamilyDolbyVision {
+		t.Errorf("hvc1 with dvcC: got codec family %s", family)
+	}
+	if family, supported := mp4.GetCodecFamily(mp4.NewVisualSampleEntryBox("dvh1")); family != mp4.CodecFamilyDolbyVision || supported {
+		t.Errorf("dvh1 without hvcC: got codec family %s and supported %t", family, supported)
+	}
+}
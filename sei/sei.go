@@ -482,6 +482,8 @@ func DecodeSEIMessage(sd *SEIData, codec Codec) (SEIMessage, error) {
 			return DecodeUserDataRegisteredSEI(sd)
 		case SEIUserDataUnregisteredType:
 			return DecodeUserDataUnregisteredSEI(sd)
+		case SEIRecoveryPointType:
+			return DecodeRecoveryPointSEI(sd)
 		default:
 			return DecodeGeneralSEI(sd), nil
 		}
@@ -5,6 +5,9 @@ import (
 	"fmt"
 )
 
+// uuidLength is the length in bytes of uuid_iso_iec_11578 in user_data_unregistered SEI.
+const uuidLength = 16
+
 // UnregisteredSEI is SEI message of type 5.
 type UnregisteredSEI struct {
 	UUID    []byte
@@ -23,7 +26,7 @@ func (s *UnregisteredSEI) Size() uint {
 
 // String provides a short description of the SEI message.
 func (s *UnregisteredSEI) String() string {
-	payloadAfterUUID := string(s.payload[16:])
+	payloadAfterUUID := string(s.UserData())
 	return fmt.Sprintf("SEI type %d, size=%d, uuid=%q, payload=%q",
 		s.Type(), s.Size(), hex.EncodeToString(s.UUID), payloadAfterUUID)
 }
@@ -33,9 +36,17 @@ func (s *UnregisteredSEI) Payload() []byte {
 	return s.payload
 }
 
+// UserData returns the user_data_payload_bytes following the UUID.
+func (s *UnregisteredSEI) UserData() []byte {
+	return s.payload[uuidLength:]
+}
+
 // DecodeUserDataUnregisteredSEI decodes an unregistered SEI message (type 5).
 func DecodeUserDataUnregisteredSEI(sd *SEIData) (SEIMessage, error) {
-	uuid := sd.payload[:16]
+	if len(sd.payload) < uuidLength {
+		return nil, fmt.Errorf("user data unregistered SEI: payload size %d less than %d", len(sd.payload), uuidLength)
+	}
+	uuid := sd.payload[:uuidLength]
 	return NewUnregisteredSEI(sd, uuid), nil
 }
 
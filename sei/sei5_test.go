@@ -27,5 +27,14 @@ func TestSEI5_UnregisteredType(t *testing.T) {
 	if wantedString != us.String() {
 		t.Errorf("Unexpected string %q, expected %q", us.String(), wantedString)
 	}
+	userData := us.(*UnregisteredSEI).UserData()
+	if string(userData) != "@@@@" {
+		t.Errorf("Unexpected user data %v", userData)
+	}
 
+	_, err = DecodeUserDataUnregisteredSEI(&SEIData{payloadType: SEIUserDataUnregisteredType,
+		payload: raw[:15]})
+	if err == nil {
+		t.Error("Expected error for payload shorter than UUID")
+	}
 }
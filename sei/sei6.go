@@ -0,0 +1,58 @@
+package sei
+
+import (
+	"bytes"
+	"fmt"
+
+	"github.com/Eyevinn/mp4ff/bits"
+)
+
+// RecoveryPointSEI is AVC SEI message 6 recovery_point.
+// Defined in ISO/IEC 14496-10 D.1.8.
+// It signals a random access point where decoding can start even if it is not an IDR picture.
+type RecoveryPointSEI struct {
+	RecoveryFrameCnt      uint
+	ExactMatchFlag        bool
+	BrokenLinkFlag        bool
+	ChangingSliceGroupIdc byte
+	payload               []byte
+}
+
+// DecodeRecoveryPointSEI decodes AVC SEI message 6 recovery_point.
+func DecodeRecoveryPointSEI(sd *SEIData) (SEIMessage, error) {
+	buf := bytes.NewBuffer(sd.Payload())
+	br := bits.NewEBSPReader(buf)
+	rp := RecoveryPointSEI{
+		payload: sd.Payload(),
+	}
+	rp.RecoveryFrameCnt = br.ReadExpGolomb()
+	rp.ExactMatchFlag = br.ReadFlag()
+	rp.BrokenLinkFlag = br.ReadFlag()
+	rp.ChangingSliceGroupIdc = byte(br.Read(2))
+	if br.AccError() != nil {
+		return nil, fmt.Errorf("recovery point SEI: %w", br.AccError())
+	}
+	return &rp, nil
+}
+
+// Type returns the SEI payload type.
+func (s *RecoveryPointSEI) Type() uint {
+	return SEIRecoveryPointType
+}
+
+// Payload returns the SEI raw rbsp payload.
+func (s *RecoveryPointSEI) Payload() []byte {
+	return s.payload
+}
+
+// Size is size in bytes of raw SEI message rbsp payload.
+func (s *RecoveryPointSEI) Size() uint {
+	return uint(len(s.payload))
+}
+
+// String returns string representation of RecoveryPoint SEI6.
+func (s *RecoveryPointSEI) String() string {
+	msgType := SEIType(s.Type())
+	return fmt.Sprintf("%s, size=%d, recoveryFrameCnt=%d, exactMatch=%t, brokenLink=%t, changingSliceGroupIdc=%d",
+		msgType, s.Size(), s.RecoveryFrameCnt, s.ExactMatchFlag, s.BrokenLinkFlag, s.ChangingSliceGroupIdc)
+}
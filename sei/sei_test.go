@@ -286,6 +286,7 @@ const (
 	seiAVCPicTiming         = "010f00011a00000300090c2e268a000003004080"
 	missingRbspTrailingBits = "01061b0509b80000"
 	seiHEVCMulti            = "000a8000000300403dc017a6900105040000be05880660404198b41080"
+	seiAVCRecoveryPoint     = "0602242080"
 	seiHEVCHDR              = "891800000300000300000300000300000300000300000300000300000300000300000300009004000003000080"
 )
 
@@ -333,6 +334,12 @@ func TestParseSEI(t *testing.T) {
 			},
 			sei.ErrRbspTrailingBitsMissing,
 		},
+		{"AVC RecoveryPoint", sei.AVC, seiAVCRecoveryPoint, nil, []uint{6},
+			[]string{
+				`SEIRecoveryPointType (6), size=2, recoveryFrameCnt=3, exactMatch=true, brokenLink=false, changingSliceGroupIdc=0`,
+			},
+			nil,
+		},
 		{"Type 0", sei.AVC, sei0Hex, nil, []uint{0}, []string{`SEIBufferingPeriodType (0), size=7, "810f1c00507440"`}, nil},
 		{"CEA-608", sei.AVC, seiCEA608Hex, nil, []uint{4},
 			[]string{`SEI type 4 CEA-608, size=52, field1: "942094ae9162e56e67ba91b9b0b0bab0b0bab031bab0b080942c942f", field2: ""`}, nil},
@@ -395,6 +402,7 @@ func TestWriteSEI(t *testing.T) {
 		hex   string
 	}{
 		{"seiHEVCHDR", sei.HEVC, seiHEVCHDR},
+		{"seiAVCRecoveryPoint", sei.AVC, seiAVCRecoveryPoint},
 	}
 	for _, tc := range cases {
 		seiNALU, _ := hex.DecodeString(tc.hex)